@@ -0,0 +1,33 @@
+use super::MyData;
+use leptos::*;
+use leptos_chartistry::*;
+
+#[component]
+pub fn Example(debug: Signal<bool>, data: Signal<Vec<MyData>>) -> impl IntoView {
+    // Our categories in the order they appear on the axis
+    let months = Categories::new([
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov",
+    ]);
+
+    let series = Series::new(move |data: &MyData| months.at(data.x as usize))
+        .bar(Bar::new(|data: &MyData| data.y1).with_name("rainfall"))
+        .with_min_y(0.0);
+    view! {
+        <Chart
+            aspect_ratio=AspectRatio::from_outer_height(300.0, 1.2)
+            debug=debug
+            series=series
+            data=data
+
+            left=TickLabels::aligned_floats()
+            // One tick per category, skipped when there's not enough space
+            bottom=TickLabels::categories()
+            inner=[
+                AxisMarker::left_edge().into_inner(),
+                AxisMarker::bottom_edge().into_inner(),
+                YGridLine::default().into_inner(),
+            ]
+            tooltip=Tooltip::left_cursor()
+        />
+    }
+}
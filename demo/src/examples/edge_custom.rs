@@ -0,0 +1,53 @@
+use super::MyData;
+use leptos::*;
+use leptos_chartistry::*;
+
+#[component]
+pub fn Example(debug: Signal<bool>, data: Signal<Vec<MyData>>) -> impl IntoView {
+    let series = Series::new(|data: &MyData| data.x)
+        .line(Line::new(|data: &MyData| data.y1).with_name("pears"))
+        .line(Line::new(|data: &MyData| data.y2).with_name("apples"));
+
+    // Our own edge component: a strip of summary stats sized like a line of text
+    let stats = CustomEdge::new(24.0, move |ctx: EdgeContext| {
+        let summary = move || {
+            data.with(|data| {
+                let total = |y: fn(&MyData) -> f64| data.iter().map(y).sum::<f64>();
+                format!(
+                    "Total pears: {:.1} · Total apples: {:.1}",
+                    total(|d| d.y1),
+                    total(|d| d.y2)
+                )
+            })
+        };
+        view! {
+            <text
+                x=move || ctx.bounds().centre_x()
+                y=move || ctx.bounds().centre_y()
+                font-size=move || ctx.font_height()
+                text-anchor="middle"
+                dominant-baseline="middle">
+                {summary}
+            </text>
+        }
+        .into_view()
+    });
+
+    view! {
+        <Chart
+            aspect_ratio=AspectRatio::from_outer_height(300.0, 1.2)
+            debug=debug
+            series=series
+            data=data
+
+            top=stats
+            left=TickLabels::aligned_floats()
+            bottom=TickLabels::aligned_floats()
+            inner=[
+                AxisMarker::left_edge().into_inner(),
+                AxisMarker::bottom_edge().into_inner(),
+            ]
+            tooltip=Tooltip::left_cursor()
+        />
+    }
+}
@@ -0,0 +1,29 @@
+use super::MyData;
+use leptos::*;
+use leptos_chartistry::*;
+
+#[component]
+pub fn Example(debug: Signal<bool>, data: Signal<Vec<MyData>>) -> impl IntoView {
+    // Counts are whole numbers: use integers for X and Y
+    let series = Series::new(|data: &MyData| data.x as u32)
+        .bar(Bar::new(|data: &MyData| data.y2.round() as i64).with_name("errors"))
+        .with_min_y(0);
+    view! {
+        <Chart
+            aspect_ratio=AspectRatio::from_outer_height(300.0, 1.2)
+            debug=debug
+            series=series
+            data=data
+
+            // Integer ticks never show fractions e.g., "2.5 errors"
+            left=TickLabels::aligned_integers()
+            bottom=TickLabels::aligned_integers()
+            inner=[
+                AxisMarker::left_edge().into_inner(),
+                AxisMarker::bottom_edge().into_inner(),
+                YGridLine::default().into_inner(),
+            ]
+            tooltip=Tooltip::left_cursor()
+        />
+    }
+}
@@ -0,0 +1,38 @@
+use super::MyData;
+use leptos::*;
+use leptos_chartistry::*;
+
+#[component]
+pub fn Example(debug: Signal<bool>, data: Signal<Vec<MyData>>) -> impl IntoView {
+    let series = Series::new(|data: &MyData| data.x)
+        .line(Line::new(|data: &MyData| data.y1 * 10.0).with_name("CPU %"))
+        // Unrelated units: project against the secondary Y axis
+        .line(
+            Line::new(|data: &MyData| data.y2 * 150.0)
+                .with_name("Requests")
+                .with_y_axis(YAxis::Secondary),
+        )
+        .with_y_range(0.0, 100.0)
+        .with_min_y2(0.0);
+    view! {
+        <Chart
+            aspect_ratio=AspectRatio::from_outer_height(300.0, 1.2)
+            debug=debug
+            series=series
+            data=data
+
+            top=Legend::end()
+            left=TickLabels::aligned_floats()
+            // Label the secondary axis on the right
+            right=TickLabels::aligned_floats().with_y_axis(YAxis::Secondary)
+            bottom=TickLabels::aligned_floats()
+            inner=[
+                AxisMarker::left_edge().into_inner(),
+                AxisMarker::right_edge().into_inner(),
+                AxisMarker::bottom_edge().into_inner(),
+                XGridLine::default().into_inner(),
+            ]
+            tooltip=Tooltip::left_cursor()
+        />
+    }
+}
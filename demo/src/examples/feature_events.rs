@@ -0,0 +1,46 @@
+use super::MyData;
+use leptos::*;
+use leptos_chartistry::*;
+
+#[component]
+pub fn Example(debug: Signal<bool>, data: Signal<Vec<MyData>>) -> impl IntoView {
+    let series = Series::new(|data: &MyData| data.x)
+        .line(Line::new(|data: &MyData| data.y1).with_name("pears"))
+        .line(Line::new(|data: &MyData| data.y2).with_name("apples"));
+    // Callbacks receive the data nearest to the mouse
+    let (hovered, set_hovered) = create_signal(None::<DataPoint<f64, f64>>);
+    let (clicked, set_clicked) = create_signal(None::<DataPoint<f64, f64>>);
+    let describe = |point: Option<DataPoint<f64, f64>>| match point {
+        Some(point) => {
+            let y = point
+                .y
+                .iter()
+                .map(|(name, y)| format!("{name}={y:.1}"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!("data[{}] at x={:.1}: {y}", point.index, point.x)
+        }
+        None => "nothing".to_string(),
+    };
+    view! {
+        <Chart
+            aspect_ratio=AspectRatio::from_outer_height(300.0, 1.2)
+            debug=debug
+            series=series
+            data=data
+            // Use these to show a side panel or navigate to a drill-down page
+            on_hover=move |point| set_hovered.set(point)
+            on_click=move |point| set_clicked.set(Some(point))
+
+            left=TickLabels::aligned_floats()
+            bottom=TickLabels::aligned_floats()
+            inner=[
+                AxisMarker::left_edge().into_inner(),
+                AxisMarker::bottom_edge().into_inner(),
+                XGuideLine::over_data().into_inner(),
+            ]
+        />
+        <p>"Hovered: " {move || describe(hovered.get())}</p>
+        <p>"Clicked: " {move || describe(clicked.get())}</p>
+    }
+}
@@ -0,0 +1,31 @@
+use super::MyData;
+use leptos::*;
+use leptos_chartistry::*;
+
+#[component]
+pub fn Example(debug: Signal<bool>, data: Signal<Vec<MyData>>) -> impl IntoView {
+    // Lots of lines: hover to highlight the nearest one
+    let series = Series::new(|data: &MyData| data.x).lines((0..8).map(|i| {
+        let scale = 1.0 + i as f64 / 4.0;
+        Line::new(move |data: &MyData| data.y1 * scale).with_name(format!("line {i}"))
+    }));
+    view! {
+        <Chart
+            aspect_ratio=AspectRatio::from_outer_height(300.0, 1.2)
+            debug=debug
+            series=series
+            data=data
+            highlight=true
+
+            left=TickLabels::aligned_floats()
+            bottom=TickLabels::aligned_floats()
+            right=Legend::middle()
+            inner=[
+                AxisMarker::left_edge().into_inner(),
+                AxisMarker::bottom_edge().into_inner(),
+                XGuideLine::over_data().into_inner(),
+            ]
+            tooltip=Tooltip::left_cursor()
+        />
+    }
+}
@@ -0,0 +1,33 @@
+use super::MyData;
+use leptos::*;
+use leptos_chartistry::*;
+
+#[component]
+pub fn Example(debug: Signal<bool>, data: Signal<Vec<MyData>>) -> impl IntoView {
+    // Charts in the same group share hover: the guide line and tooltip follow
+    let sync = SyncGroup::new();
+    let chart = move |series: Series<MyData, f64, f64>| {
+        view! {
+            <Chart
+                aspect_ratio=AspectRatio::from_outer_height(150.0, 2.4)
+                debug=debug
+                series=series
+                data=data
+                sync=sync
+
+                left=TickLabels::aligned_floats()
+                bottom=TickLabels::aligned_floats()
+                inner=[
+                    AxisMarker::left_edge().into_inner(),
+                    AxisMarker::bottom_edge().into_inner(),
+                    XGuideLine::over_data().into_inner(),
+                ]
+                tooltip=Tooltip::left_cursor()
+            />
+        }
+    };
+    view! {
+        {chart(Series::new(|data: &MyData| data.x).line(Line::new(|data: &MyData| data.y1).with_name("y1")))}
+        {chart(Series::new(|data: &MyData| data.x).line(Line::new(|data: &MyData| data.y2).with_name("y2")))}
+    }
+}
@@ -0,0 +1,41 @@
+use super::MyData;
+use leptos::*;
+use leptos_chartistry::*;
+
+#[component]
+pub fn Example(debug: Signal<bool>, data: Signal<Vec<MyData>>) -> impl IntoView {
+    // Start with apples (the second line, ID 1) hidden. Click a legend entry to hide or show it
+    let names = ["pears", "apples"];
+    let series = Series::new(|data: &MyData| data.x)
+        .line(Line::new(|data: &MyData| data.y1).with_name(names[0]))
+        .line(Line::new(|data: &MyData| data.y2).with_name(names[1]))
+        .with_hidden([1]);
+    // Hidden series can be read (and set) by the app
+    let hidden = series.hidden;
+    let summary = move || {
+        hidden.with(|hidden| {
+            let mut ids = hidden.iter().copied().collect::<Vec<_>>();
+            ids.sort_unstable();
+            let names = ids.into_iter().map(|id| names[id]).collect::<Vec<_>>();
+            format!("Hidden: {}", names.join(", "))
+        })
+    };
+    view! {
+        <Chart
+            aspect_ratio=AspectRatio::from_outer_height(300.0, 1.2)
+            debug=debug
+            series=series
+            data=data
+
+            top=Legend::middle()
+            left=TickLabels::aligned_floats()
+            bottom=TickLabels::aligned_floats()
+            inner=[
+                AxisMarker::left_edge().into_inner(),
+                AxisMarker::bottom_edge().into_inner(),
+            ]
+            tooltip=Tooltip::left_cursor()
+        />
+        <p>{summary}</p>
+    }
+}
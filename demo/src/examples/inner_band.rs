@@ -0,0 +1,35 @@
+use super::MyData;
+use leptos::*;
+use leptos_chartistry::*;
+
+#[component]
+pub fn Example(debug: Signal<bool>, data: Signal<Vec<MyData>>) -> impl IntoView {
+    let series = Series::new(|data: &MyData| data.x)
+        .line(Line::new(|data: &MyData| data.y1).with_name("pears"))
+        .line(Line::new(|data: &MyData| data.y2).with_name("apples"));
+    view! {
+        <Chart
+            aspect_ratio=AspectRatio::from_outer_height(300.0, 1.2)
+            debug=debug
+            series=series
+            data=data
+
+            left=TickLabels::aligned_floats()
+            bottom=TickLabels::aligned_floats()
+            inner=[
+                // Bands are drawn beneath the series
+                YBand::new(2.0, 4.0)
+                    .with_label("target")
+                    .with_colour(Colour::from_rgb(0x71, 0xc6, 0x14))
+                    .into_inner(),
+                XBand::new(5.0, 7.0)
+                    .with_label("incident")
+                    .with_colour(Colour::from_rgb(0xF5, 0x32, 0x5B))
+                    .with_opacity(0.15)
+                    .into_inner(),
+                AxisMarker::left_edge().into_inner(),
+                AxisMarker::bottom_edge().into_inner(),
+            ]
+        />
+    }
+}
@@ -0,0 +1,43 @@
+use super::MyData;
+use leptos::*;
+use leptos_chartistry::*;
+
+#[component]
+pub fn Example(debug: Signal<bool>, data: Signal<Vec<MyData>>) -> impl IntoView {
+    let series = Series::new(|data: &MyData| data.x)
+        .line(|data: &MyData| data.y1)
+        .line(|data: &MyData| data.y2);
+    // The brush writes the selected X range to this signal
+    let selected = RwSignal::new(None);
+    // Use the selection elsewhere on the page e.g., to filter data
+    let summary = move || match selected.get() {
+        Some((first, last)) => {
+            let total = data.with(|data| {
+                data.iter()
+                    .filter(|d| first <= d.x && d.x <= last)
+                    .map(|d| d.y1)
+                    .sum::<f64>()
+            });
+            format!("Sum of y1 from {first:.1} to {last:.1} is {total:.1}")
+        }
+        None => "Drag across the chart to select a range".to_string(),
+    };
+    view! {
+        <Chart
+            aspect_ratio=AspectRatio::from_outer_height(300.0, 1.2)
+            debug=debug
+            series=series
+            data=data
+
+            left=TickLabels::aligned_floats()
+            bottom=TickLabels::aligned_floats()
+            inner=[
+                AxisMarker::left_edge().into_inner(),
+                AxisMarker::bottom_edge().into_inner(),
+                // Drag to select, click to clear
+                XBrush::new(selected).into_inner(),
+            ]
+        />
+        <p>{summary}</p>
+    }
+}
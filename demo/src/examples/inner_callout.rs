@@ -0,0 +1,34 @@
+use super::MyData;
+use leptos::*;
+use leptos_chartistry::*;
+
+#[component]
+pub fn Example(debug: Signal<bool>, data: Signal<Vec<MyData>>) -> impl IntoView {
+    let series = Series::new(|data: &MyData| data.x)
+        .line(Line::new(|data: &MyData| data.y1).with_name("pears"))
+        .line(Line::new(|data: &MyData| data.y2).with_name("apples"));
+    view! {
+        <Chart
+            aspect_ratio=AspectRatio::from_outer_height(300.0, 1.2)
+            debug=debug
+            series=series
+            data=data
+
+            left=TickLabels::aligned_floats()
+            bottom=TickLabels::aligned_floats()
+            inner=[
+                AxisMarker::left_edge().into_inner(),
+                AxisMarker::bottom_edge().into_inner(),
+                // Call out points from our data: labels are nudged apart
+                Callouts::from_data(data, |d: &MyData| {
+                    (d.y2 > 8.0).then(|| (d.x, d.y2, format!("peak {:.1}", d.y2)))
+                })
+                .into_inner(),
+                // Or give a list of points
+                Callouts::new([(3.0, 5.5, "release"), (3.0, 3.0, "rollback")])
+                    .with_colour(Colour::from_rgb(0xF5, 0x32, 0x5B))
+                    .into_inner(),
+            ]
+        />
+    }
+}
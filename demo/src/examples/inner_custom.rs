@@ -0,0 +1,48 @@
+use super::MyData;
+use leptos::*;
+use leptos_chartistry::*;
+
+#[component]
+pub fn Example(debug: Signal<bool>, data: Signal<Vec<MyData>>) -> impl IntoView {
+    let series = Series::new(|data: &MyData| data.x)
+        .line(Line::new(|data: &MyData| data.y1).with_name("pears"))
+        .line(Line::new(|data: &MyData| data.y2).with_name("apples"));
+
+    // Our own inner component: shades the column of the hovered data
+    let column = CustomInner::new(|ctx: ChartContext<f64, f64>| {
+        let shade = move || {
+            let x = ctx.hover_x()?;
+            let inner = ctx.inner_bounds();
+            let (left, right) = (ctx.to_svg_x(&(x - 0.5)), ctx.to_svg_x(&(x + 0.5)));
+            let (left, right) = (left.max(inner.left_x()), right.min(inner.right_x()));
+            Some(view! {
+                <rect
+                    x=left
+                    y=inner.top_y()
+                    width=right - left
+                    height=inner.height()
+                    fill="#FFCA00"
+                    fill-opacity=0.3 />
+            })
+        };
+        view!( <g>{shade}</g> ).into_view()
+    });
+
+    view! {
+        <Chart
+            aspect_ratio=AspectRatio::from_outer_height(300.0, 1.2)
+            debug=debug
+            series=series
+            data=data
+
+            left=TickLabels::aligned_floats()
+            bottom=TickLabels::aligned_floats()
+            inner=[
+                AxisMarker::left_edge().into_inner(),
+                AxisMarker::bottom_edge().into_inner(),
+                column.into_inner(),
+            ]
+            tooltip=Tooltip::left_cursor()
+        />
+    }
+}
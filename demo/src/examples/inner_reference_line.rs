@@ -0,0 +1,34 @@
+use super::MyData;
+use leptos::*;
+use leptos_chartistry::*;
+
+#[component]
+pub fn Example(debug: Signal<bool>, data: Signal<Vec<MyData>>) -> impl IntoView {
+    let series = Series::new(|data: &MyData| data.x)
+        .line(Line::new(|data: &MyData| data.y1).with_name("pears"))
+        .line(Line::new(|data: &MyData| data.y2).with_name("apples"));
+    view! {
+        <Chart
+            aspect_ratio=AspectRatio::from_outer_height(300.0, 1.2)
+            debug=debug
+            series=series
+            data=data
+
+            left=TickLabels::aligned_floats()
+            bottom=TickLabels::aligned_floats()
+            inner=[
+                AxisMarker::left_edge().into_inner(),
+                AxisMarker::bottom_edge().into_inner(),
+                // Mark an event at a given X value
+                XReferenceLine::new(2.0).with_label("deploy").into_inner(),
+                // A threshold outside our data extends the Y axis to include it
+                YReferenceLine::new(10.0)
+                    .with_label("threshold")
+                    .with_colour(Colour::from_rgb(0xF5, 0x32, 0x5B))
+                    .with_dash(Dash::Dotted)
+                    .with_width(2.0)
+                    .into_inner(),
+            ]
+        />
+    }
+}
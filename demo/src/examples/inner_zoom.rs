@@ -0,0 +1,43 @@
+use super::MyData;
+use leptos::*;
+use leptos_chartistry::*;
+
+#[component]
+pub fn Example(debug: Signal<bool>, data: Signal<Vec<MyData>>) -> impl IntoView {
+    let series = Series::new(|data: &MyData| data.x)
+        .line(|data: &MyData| data.y1)
+        .line(|data: &MyData| data.y2);
+    // The zoomed ranges are signals: read them or set them to None to reset
+    let (zoom_x, zoom_y) = (series.zoom_x, series.zoom_y);
+    let reset = move |_| {
+        zoom_x.set(None);
+        zoom_y.set(None);
+    };
+    let visible_x = move || {
+        zoom_x
+            .get()
+            .map(|(first, last)| format!("{first:.1} to {last:.1}"))
+            .unwrap_or_else(|| "all".to_string())
+    };
+    view! {
+        <Chart
+            aspect_ratio=AspectRatio::from_outer_height(300.0, 1.2)
+            debug=debug
+            series=series
+            data=data
+
+            left=TickLabels::aligned_floats()
+            bottom=TickLabels::aligned_floats()
+            inner=[
+                AxisMarker::left_edge().into_inner(),
+                AxisMarker::bottom_edge().into_inner(),
+                // Drag to zoom, shift+drag to pan, wheel to zoom and double click to reset
+                Zoom::default().into_inner(),
+            ]
+        />
+        <p>
+            "Showing X: " {visible_x} " "
+            <button on:click=reset>"Reset zoom"</button>
+        </p>
+    }
+}
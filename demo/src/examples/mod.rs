@@ -1,24 +1,47 @@
 pub mod aspect_sunspots;
+pub mod edge_categories;
+pub mod edge_custom;
+pub mod edge_integer_ticks;
 pub mod edge_layout;
 pub mod edge_legend;
 pub mod edge_rotated_label;
+pub mod edge_secondary_axis;
 pub mod edge_tick_labels;
 pub mod feature_colours;
 pub mod feature_css;
+pub mod feature_events;
+pub mod feature_highlight;
 pub mod feature_line_gradient;
 pub mod feature_markers;
 pub mod feature_markers_2;
+pub mod feature_sync;
+pub mod feature_toggle_series;
 pub mod feature_tooltip;
 pub mod inner_axis_marker;
+pub mod inner_band;
+pub mod inner_brush;
+pub mod inner_callout;
+pub mod inner_custom;
 pub mod inner_grid_line;
 pub mod inner_guide_line;
 pub mod inner_layout;
 pub mod inner_legend;
+pub mod inner_reference_line;
+pub mod inner_zoom;
 pub mod interpolation_mixed;
 pub mod interpolation_stepped;
 pub mod series_bar;
+pub mod series_bar_horizontal;
+pub mod series_bar_stack;
+pub mod series_box_plot;
+pub mod series_candlestick;
+pub mod series_histogram;
 pub mod series_line;
+pub mod series_line_area;
+pub mod series_line_error;
+pub mod series_line_log;
 pub mod series_line_stack;
+pub mod series_scatter;
 
 use leptos::signal_prelude::*;
 
@@ -0,0 +1,28 @@
+use super::MyData;
+use leptos::*;
+use leptos_chartistry::*;
+
+#[component]
+pub fn Example(debug: Signal<bool>, data: Signal<Vec<MyData>>) -> impl IntoView {
+    // Horizontal bars are placed at Y and extend along X
+    let series = Series::new(|data: &MyData| data.y1)
+        .bar(Bar::new(|data: &MyData| data.x).with_orientation(BarOrientation::Horizontal))
+        // Start from zero
+        .with_min_x(0.0);
+    view! {
+        <Chart
+            aspect_ratio=AspectRatio::from_outer_height(300.0, 1.2)
+            debug=debug
+            series=series
+            data=data
+
+            left=TickLabels::aligned_floats()
+            bottom=TickLabels::aligned_floats()
+            inner=[
+                AxisMarker::left_edge().into_inner(),
+                AxisMarker::bottom_edge().into_inner(),
+                XGridLine::default().into_inner(),
+            ]
+        />
+    }
+}
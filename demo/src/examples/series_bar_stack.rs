@@ -0,0 +1,33 @@
+use super::MyData;
+use leptos::*;
+use leptos_chartistry::*;
+
+#[component]
+pub fn Example(debug: Signal<bool>, data: Signal<Vec<MyData>>) -> impl IntoView {
+    // Bars in a stack share the same slot
+    let stack = Stack::new()
+        .bar(Bar::new(|data: &MyData| data.y1).with_name("fairies"))
+        .bar(Bar::new(|data: &MyData| data.y2).with_name("pixies"));
+
+    let series = Series::new(|data: &MyData| data.x)
+        .stack(stack)
+        // Start from zero
+        .with_min_y(0.0);
+    view! {
+        <Chart
+            aspect_ratio=AspectRatio::from_outer_height(300.0, 1.2)
+            debug=debug
+            series=series
+            data=data
+
+            left=TickLabels::aligned_floats()
+            bottom=Legend::end()
+            inner=[
+                AxisMarker::left_edge().into_inner(),
+                AxisMarker::bottom_edge().into_inner(),
+                YGridLine::default().into_inner(),
+            ]
+            tooltip=Tooltip::left_cursor().show_x_ticks(false)
+        />
+    }
+}
@@ -0,0 +1,35 @@
+use super::MyData;
+use leptos::*;
+use leptos_chartistry::*;
+
+#[component]
+pub fn Example(debug: Signal<bool>, data: Signal<Vec<MyData>>) -> impl IntoView {
+    // Quartiles are precomputed. Here we pretend y1 and y2 are the first and third quartiles
+    let series = Series::new(|data: &MyData| data.x).box_plot(
+        BoxPlot::new(
+            |data: &MyData| data.y1.min(data.y2) - 1.0,
+            |data: &MyData| data.y1.min(data.y2),
+            |data: &MyData| (data.y1 + data.y2) / 2.0,
+            |data: &MyData| data.y1.max(data.y2),
+            |data: &MyData| data.y1.max(data.y2) + 1.0,
+        )
+        .with_outliers(|data: &MyData| vec![data.y1.max(data.y2) + 1.5])
+        .with_name("latency"),
+    );
+    view! {
+        <Chart
+            aspect_ratio=AspectRatio::from_outer_height(300.0, 1.2)
+            debug=debug
+            series=series
+            data=data
+
+            left=TickLabels::aligned_floats()
+            inner=[
+                AxisMarker::left_edge().into_inner(),
+                AxisMarker::bottom_edge().into_inner(),
+                YGridLine::default().into_inner(),
+            ]
+            tooltip=Tooltip::left_cursor()
+        />
+    }
+}
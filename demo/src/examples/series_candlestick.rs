@@ -0,0 +1,33 @@
+use super::MyData;
+use leptos::*;
+use leptos_chartistry::*;
+
+#[component]
+pub fn Example(debug: Signal<bool>, data: Signal<Vec<MyData>>) -> impl IntoView {
+    // Pretend y1 is the open and y2 is the close. High and low add a little either side
+    let series = Series::new(|data: &MyData| data.x).candlestick(
+        Candlestick::new(
+            |data: &MyData| data.y1,
+            |data: &MyData| data.y1.max(data.y2) + 0.5,
+            |data: &MyData| data.y1.min(data.y2) - 0.5,
+            |data: &MyData| data.y2,
+        )
+        .with_name("price"),
+    );
+    view! {
+        <Chart
+            aspect_ratio=AspectRatio::from_outer_height(300.0, 1.2)
+            debug=debug
+            series=series
+            data=data
+
+            left=TickLabels::aligned_floats()
+            inner=[
+                AxisMarker::left_edge().into_inner(),
+                AxisMarker::bottom_edge().into_inner(),
+                YGridLine::default().into_inner(),
+            ]
+            tooltip=Tooltip::left_cursor()
+        />
+    }
+}
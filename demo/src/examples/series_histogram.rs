@@ -0,0 +1,32 @@
+use super::MyData;
+use leptos::*;
+use leptos_chartistry::*;
+
+#[component]
+pub fn Example(debug: Signal<bool>, data: Signal<Vec<MyData>>) -> impl IntoView {
+    // Raw samples: every Y value from our data
+    let samples = Signal::derive(move || {
+        data.with(|data| data.iter().flat_map(|d| [d.y1, d.y2]).collect::<Vec<_>>())
+    });
+    // Sort samples into bins. Try Binning::Count(5) or Binning::Width(0.5)
+    let histogram = Histogram::new(Binning::FreedmanDiaconis).with_name("readings");
+    let bins = histogram.bins(samples);
+    view! {
+        <Chart
+            aspect_ratio=AspectRatio::from_outer_height(300.0, 1.2)
+            debug=debug
+            series=histogram.series()
+            data=bins
+
+            left=TickLabels::aligned_floats()
+            // Ticks on the edges of each bin
+            bottom=histogram.tick_labels(bins)
+            inner=[
+                AxisMarker::left_edge().into_inner(),
+                AxisMarker::bottom_edge().into_inner(),
+                YGridLine::default().into_inner(),
+            ]
+            tooltip=Tooltip::left_cursor()
+        />
+    }
+}
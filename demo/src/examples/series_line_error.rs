@@ -0,0 +1,38 @@
+use super::MyData;
+use leptos::*;
+use leptos_chartistry::*;
+
+#[component]
+pub fn Example(debug: Signal<bool>, data: Signal<Vec<MyData>>) -> impl IntoView {
+    // Lower and upper values are drawn as a band or whiskers. Both are included in the Y range
+    let series = Series::new(|data: &MyData| data.x)
+        .line(
+            Line::new(|data: &MyData| data.y1)
+                .with_name("forecast")
+                .with_error(|data: &MyData| data.y1 * 0.8, |data: &MyData| data.y1 * 1.2)
+                .with_error_bars(ErrorStyle::Band),
+        )
+        .line(
+            Line::new(|data: &MyData| data.y2)
+                .with_name("measured")
+                .with_error(|data: &MyData| data.y2 - 0.5, |data: &MyData| data.y2 + 0.5),
+        );
+    view! {
+        <Chart
+            aspect_ratio=AspectRatio::from_outer_height(300.0, 1.2)
+            debug=debug
+            series=series
+            data=data
+
+            left=TickLabels::aligned_floats()
+            bottom=Legend::end()
+            inner=[
+                AxisMarker::left_edge().into_inner(),
+                AxisMarker::bottom_edge().into_inner(),
+                XGridLine::default().into_inner(),
+                YGridLine::default().into_inner(),
+            ]
+            tooltip=Tooltip::left_cursor().show_x_ticks(false)
+        />
+    }
+}
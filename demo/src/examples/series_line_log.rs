@@ -0,0 +1,31 @@
+use super::MyData;
+use leptos::*;
+use leptos_chartistry::*;
+
+#[component]
+pub fn Example(debug: Signal<bool>, data: Signal<Vec<MyData>>) -> impl IntoView {
+    // Growth spanning several orders of magnitude
+    let series = Series::new(|data: &MyData| data.x)
+        .line(Line::new(|data: &MyData| 10_f64.powf(data.y1)).with_name("growth"))
+        .line(Line::new(|data: &MyData| 10_f64.powf(data.y2 / 2.0)).with_name("spread"))
+        // Map Y through log10
+        .with_scale_y(Scale::Log10);
+    view! {
+        <Chart
+            aspect_ratio=AspectRatio::from_outer_height(300.0, 1.2)
+            debug=debug
+            series=series
+            data=data
+
+            // Decade ticks: 1, 10, 100, etc.
+            left=TickLabels::log_floats()
+            bottom=TickLabels::aligned_floats()
+            inner=[
+                AxisMarker::left_edge().into_inner(),
+                AxisMarker::bottom_edge().into_inner(),
+                YGridLine::default().into_inner(),
+            ]
+            tooltip=Tooltip::left_cursor()
+        />
+    }
+}
@@ -12,19 +12,42 @@ use web_sys::{HtmlDialogElement, MouseEvent};
 pub enum Example {
     Line,
     StackedLine,
+    Area,
+    ErrorBars,
     Bar,
+    StackedBar,
+    HorizontalBar,
+    Scatter,
+    Candlestick,
+    BoxPlot,
+    Histogram,
     Legend,
     TickLabels,
+    Categories,
+    IntegerTicks,
+    LogScale,
+    SecondaryAxis,
     RotatedLabel,
     EdgeLayout,
+    CustomEdge,
     AxisMarker,
+    Zoom,
+    Brush,
+    ReferenceLine,
+    Band,
+    Callout,
     GridLine,
     GuideLine,
     InsetLegend,
     InnerLayout,
+    CustomInner,
     MixedInterpolation,
     Stepped,
     Tooltip,
+    Sync,
+    ToggleSeries,
+    Highlight,
+    Events,
     Colours,
     Markers,
     Markers2,
@@ -43,19 +66,42 @@ impl Example {
         match self {
             Self::Line => "Line chart",
             Self::StackedLine => "Stacked line chart",
+            Self::Area => "Area chart",
+            Self::ErrorBars => "Error bars",
             Self::Bar => "Bar chart",
+            Self::StackedBar => "Stacked bar chart",
+            Self::HorizontalBar => "Horizontal bar chart",
+            Self::Scatter => "Scatter chart",
+            Self::Candlestick => "Candlestick chart",
+            Self::BoxPlot => "Box plot",
+            Self::Histogram => "Histogram",
             Self::Legend => "Legend",
             Self::TickLabels => "Tick labels",
+            Self::Categories => "Categorical axis",
+            Self::IntegerTicks => "Integer ticks",
+            Self::LogScale => "Log scale",
+            Self::SecondaryAxis => "Secondary Y axis",
             Self::RotatedLabel => "Rotated label",
             Self::EdgeLayout => "Combined edge layout",
+            Self::CustomEdge => "Custom edge component",
             Self::AxisMarker => "Axis marker",
+            Self::Zoom => "Zoom and pan",
+            Self::Brush => "X brush",
+            Self::ReferenceLine => "Reference lines",
+            Self::Band => "Bands",
+            Self::Callout => "Callouts",
             Self::GridLine => "Grid line",
             Self::GuideLine => "Guide line",
             Self::InsetLegend => "Inset legend",
             Self::InnerLayout => "Combined inner layout",
+            Self::CustomInner => "Custom inner component",
             Self::MixedInterpolation => "Linear and monotone",
             Self::Stepped => "Stepped",
             Self::Tooltip => "Tooltip",
+            Self::Sync => "Synchronised charts",
+            Self::ToggleSeries => "Toggle series",
+            Self::Highlight => "Highlight nearest series",
+            Self::Events => "Click and hover events",
             Self::Colours => "Colour",
             Self::Markers => "Point markers",
             Self::Markers2 => "Point markers 2",
@@ -68,19 +114,42 @@ impl Example {
         match self {
             Self::Line => "series-line",
             Self::StackedLine => "series-line-stack",
+            Self::Area => "series-line-area",
+            Self::ErrorBars => "series-line-error",
             Self::Bar => "series-bar",
+            Self::StackedBar => "series-bar-stack",
+            Self::HorizontalBar => "series-bar-horizontal",
+            Self::Scatter => "series-scatter",
+            Self::Candlestick => "series-candlestick",
+            Self::BoxPlot => "series-box-plot",
+            Self::Histogram => "series-histogram",
             Self::Legend => "edge-legend",
             Self::TickLabels => "edge-tick-labels",
+            Self::Categories => "edge-categories",
+            Self::IntegerTicks => "edge-integer-ticks",
+            Self::LogScale => "series-line-log",
+            Self::SecondaryAxis => "edge-secondary-axis",
             Self::RotatedLabel => "edge-rotated-label",
             Self::EdgeLayout => "edge-layout",
+            Self::CustomEdge => "edge-custom",
             Self::AxisMarker => "inner-axis-marker",
+            Self::Zoom => "inner-zoom",
+            Self::Brush => "inner-brush",
+            Self::ReferenceLine => "inner-reference-line",
+            Self::Band => "inner-band",
+            Self::Callout => "inner-callout",
             Self::GridLine => "inner-grid-line",
             Self::GuideLine => "inner-guide-line",
             Self::InsetLegend => "inner-legend",
             Self::InnerLayout => "inner-layout",
+            Self::CustomInner => "inner-custom",
             Self::MixedInterpolation => "interpolation-mixed",
             Self::Stepped => "interpolation-stepped",
             Self::Tooltip => "feature-tooltip",
+            Self::Sync => "feature-sync",
+            Self::ToggleSeries => "feature-toggle-series",
+            Self::Highlight => "feature-highlight",
+            Self::Events => "feature-events",
             Self::Colours => "feature-colours",
             Self::Markers => "feature-markers",
             Self::Markers2 => "feature-markers-2",
@@ -93,19 +162,42 @@ impl Example {
         match self {
             Self::Line => "A simple line chart.",
             Self::StackedLine => "A stacked line chart.",
+            Self::Area => "A stacked area chart.",
+            Self::ErrorBars => "A line with a shaded confidence band and a line with whiskers.",
             Self::Bar => "A simple bar chart.",
+            Self::StackedBar => "A stacked bar chart.",
+            Self::HorizontalBar => "A bar chart with bars extending along the X axis.",
+            Self::Scatter => "A scatter chart of unconnected points.",
+            Self::Candlestick => "A candlestick chart of open, high, low and close values.",
+            Self::BoxPlot => "A box plot of precomputed quartiles with outliers.",
+            Self::Histogram => "A histogram of raw samples sorted into bins.",
             Self::Legend => "Add legends to your chart edges.",
             Self::TickLabels => "Add tick labels and auto-pick nice values.",
+            Self::Categories => "Label an axis with named categories.",
+            Self::IntegerTicks => "Use whole number ticks for counts.",
+            Self::LogScale => "Use a log scale for data spanning magnitudes.",
+            Self::SecondaryAxis => "Overlay data with unrelated units.",
             Self::RotatedLabel => "Add rotated labels to your chart.",
             Self::EdgeLayout => "A more complete example of all edge options.",
+            Self::CustomEdge => "Add your own component to the chart's edge.",
             Self::AxisMarker => "Add axis markers to the edges of your chart area.",
+            Self::Zoom => "Drag, scroll and pan to zoom in.",
+            Self::Brush => "Select an X range to use elsewhere.",
+            Self::ReferenceLine => "Mark fixed X or Y values.",
+            Self::Band => "Shade a span between two X or Y values.",
+            Self::Callout => "Label data points with nudged callouts.",
             Self::GridLine => "Add grid lines aligned to your tick labels.",
             Self::GuideLine => "Add guide lines to your mouse.",
             Self::InsetLegend => "Add a legend inside your chart area.",
             Self::InnerLayout => "A more complete example of all inner options.",
+            Self::CustomInner => "Draw your own SVG inside the chart.",
             Self::MixedInterpolation => "Change the interpolation of your lines.",
             Self::Stepped => "Change the interpolation of your lines to stepped.",
             Self::Tooltip => "Add a mouse tooltip to your chart.",
+            Self::Sync => "Share hover between charts.",
+            Self::ToggleSeries => "Click the legend to hide or show series.",
+            Self::Highlight => "Emphasise the series nearest the mouse.",
+            Self::Events => "Respond to clicks and hovers on data.",
             Self::Colours => "Change the colours of your chart.",
             Self::Markers => "Add point markers to your lines.",
             Self::Markers2 => "Another way to add point markers to your lines.",
@@ -118,19 +210,42 @@ impl Example {
         match self {
             Self::Line => include_example_hl!("series_line"),
             Self::StackedLine => include_example_hl!("series_line_stack"),
+            Self::Area => include_example_hl!("series_line_area"),
+            Self::ErrorBars => include_example_hl!("series_line_error"),
             Self::Bar => include_example_hl!("series_bar"),
+            Self::StackedBar => include_example_hl!("series_bar_stack"),
+            Self::HorizontalBar => include_example_hl!("series_bar_horizontal"),
+            Self::Scatter => include_example_hl!("series_scatter"),
+            Self::Candlestick => include_example_hl!("series_candlestick"),
+            Self::BoxPlot => include_example_hl!("series_box_plot"),
+            Self::Histogram => include_example_hl!("series_histogram"),
             Self::Legend => include_example_hl!("edge_legend"),
             Self::TickLabels => include_example_hl!("edge_tick_labels"),
+            Self::Categories => include_example_hl!("edge_categories"),
+            Self::IntegerTicks => include_example_hl!("edge_integer_ticks"),
+            Self::LogScale => include_example_hl!("series_line_log"),
+            Self::SecondaryAxis => include_example_hl!("edge_secondary_axis"),
             Self::RotatedLabel => include_example_hl!("edge_rotated_label"),
             Self::EdgeLayout => include_example_hl!("edge_layout"),
+            Self::CustomEdge => include_example_hl!("edge_custom"),
             Self::AxisMarker => include_example_hl!("inner_axis_marker"),
+            Self::Zoom => include_example_hl!("inner_zoom"),
+            Self::Brush => include_example_hl!("inner_brush"),
+            Self::ReferenceLine => include_example_hl!("inner_reference_line"),
+            Self::Band => include_example_hl!("inner_band"),
+            Self::Callout => include_example_hl!("inner_callout"),
             Self::GridLine => include_example_hl!("inner_grid_line"),
             Self::GuideLine => include_example_hl!("inner_guide_line"),
             Self::InsetLegend => include_example_hl!("inner_legend"),
             Self::InnerLayout => include_example_hl!("inner_layout"),
+            Self::CustomInner => include_example_hl!("inner_custom"),
             Self::MixedInterpolation => include_example_hl!("interpolation_mixed"),
             Self::Stepped => include_example_hl!("interpolation_stepped"),
             Self::Tooltip => include_example_hl!("feature_tooltip"),
+            Self::Sync => include_example_hl!("feature_sync"),
+            Self::ToggleSeries => include_example_hl!("feature_toggle_series"),
+            Self::Highlight => include_example_hl!("feature_highlight"),
+            Self::Events => include_example_hl!("feature_events"),
             Self::Colours => include_example_hl!("feature_colours"),
             Self::Markers => include_example_hl!("feature_markers"),
             Self::Markers2 => include_example_hl!("feature_markers_2"),
@@ -153,19 +268,42 @@ impl Example {
         match self {
             Self::Line => view!(<series_line::Example debug=de data=da />),
             Self::StackedLine => view!(<series_line_stack::Example debug=de data=da />),
+            Self::Area => view!(<series_line_area::Example debug=de data=da />),
+            Self::ErrorBars => view!(<series_line_error::Example debug=de data=da />),
             Self::Bar => view!(<series_bar::Example debug=de data=da />),
+            Self::StackedBar => view!(<series_bar_stack::Example debug=de data=da />),
+            Self::HorizontalBar => view!(<series_bar_horizontal::Example debug=de data=da />),
+            Self::Scatter => view!(<series_scatter::Example debug=de data=da />),
+            Self::Candlestick => view!(<series_candlestick::Example debug=de data=da />),
+            Self::BoxPlot => view!(<series_box_plot::Example debug=de data=da />),
+            Self::Histogram => view!(<series_histogram::Example debug=de data=da />),
             Self::Legend => view!(<edge_legend::Example debug=de data=da />),
             Self::TickLabels => view!(<edge_tick_labels::Example debug=de data=da />),
+            Self::Categories => view!(<edge_categories::Example debug=de data=da />),
+            Self::IntegerTicks => view!(<edge_integer_ticks::Example debug=de data=da />),
+            Self::LogScale => view!(<series_line_log::Example debug=de data=da />),
+            Self::SecondaryAxis => view!(<edge_secondary_axis::Example debug=de data=da />),
             Self::RotatedLabel => view!(<edge_rotated_label::Example debug=de data=da />),
             Self::EdgeLayout => view!(<edge_layout::Example debug=de data=da />),
+            Self::CustomEdge => view!(<edge_custom::Example debug=de data=da />),
             Self::AxisMarker => view!(<inner_axis_marker::Example debug=de data=da />),
+            Self::Zoom => view!(<inner_zoom::Example debug=de data=da />),
+            Self::Brush => view!(<inner_brush::Example debug=de data=da />),
+            Self::ReferenceLine => view!(<inner_reference_line::Example debug=de data=da />),
+            Self::Band => view!(<inner_band::Example debug=de data=da />),
+            Self::Callout => view!(<inner_callout::Example debug=de data=da />),
             Self::GridLine => view!(<inner_grid_line::Example debug=de data=da />),
             Self::GuideLine => view!(<inner_guide_line::Example debug=de data=da />),
             Self::InsetLegend => view!(<inner_legend::Example debug=de data=da />),
             Self::InnerLayout => view!(<inner_layout::Example debug=de data=da />),
+            Self::CustomInner => view!(<inner_custom::Example debug=de data=da />),
             Self::MixedInterpolation => view!(<interpolation_mixed::Example debug=de data=da />),
             Self::Stepped => view!(<interpolation_stepped::Example debug=de data=da />),
             Self::Tooltip => view!(<feature_tooltip::Example debug=de data=da />),
+            Self::Sync => view!(<feature_sync::Example debug=de data=da />),
+            Self::ToggleSeries => view!(<feature_toggle_series::Example debug=de data=da />),
+            Self::Highlight => view!(<feature_highlight::Example debug=de data=da />),
+            Self::Events => view!(<feature_events::Example debug=de data=da />),
             Self::Colours => view!(<feature_colours::Example debug=de data=da />),
             Self::Markers => view!(<feature_markers::Example debug=de data=da />),
             Self::Markers2 => view!(<feature_markers_2::Example debug=de data=da />),
@@ -262,7 +400,15 @@ pub fn Examples() -> impl IntoView {
                 <div class="cards">
                     <Card example=Example::Line />
                     <Card example=Example::StackedLine />
+                    <Card example=Example::Area />
+                    <Card example=Example::ErrorBars />
                     <Card example=Example::Bar />
+                    <Card example=Example::StackedBar />
+                    <Card example=Example::HorizontalBar />
+                    <Card example=Example::Scatter />
+                    <Card example=Example::Candlestick />
+                    <Card example=Example::BoxPlot />
+                    <Card example=Example::Histogram />
                 </div>
             </div>
 
@@ -271,8 +417,13 @@ pub fn Examples() -> impl IntoView {
                 <div class="cards">
                     <Card example=Example::Legend />
                     <Card example=Example::TickLabels />
+                    <Card example=Example::Categories />
+                    <Card example=Example::IntegerTicks />
+                    <Card example=Example::LogScale />
+                    <Card example=Example::SecondaryAxis />
                     <Card example=Example::RotatedLabel />
                     <Card example=Example::EdgeLayout />
+                    <Card example=Example::CustomEdge />
                 </div>
             </div>
 
@@ -280,10 +431,16 @@ pub fn Examples() -> impl IntoView {
                 <h2><a href="examples.html#inner">"Inner layout options"</a></h2>
                 <div class="cards">
                     <Card example=Example::AxisMarker />
+                    <Card example=Example::Zoom />
+                    <Card example=Example::Brush />
+                    <Card example=Example::ReferenceLine />
+                    <Card example=Example::Band />
+                    <Card example=Example::Callout />
                     <Card example=Example::GridLine />
                     <Card example=Example::GuideLine />
                     <Card example=Example::InsetLegend />
                     <Card example=Example::InnerLayout />
+                    <Card example=Example::CustomInner />
                 </div>
             </div>
 
@@ -299,6 +456,10 @@ pub fn Examples() -> impl IntoView {
                 <h2><a href="examples.html#features">"Features"</a></h2>
                 <div class="cards">
                     <Card example=Example::Tooltip />
+                    <Card example=Example::Sync />
+                    <Card example=Example::ToggleSeries />
+                    <Card example=Example::Highlight />
+                    <Card example=Example::Events />
                     <Card example=Example::Colours />
                     <Card example=Example::LineGradient />
                     <Card example=Example::Markers />
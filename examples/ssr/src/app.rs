@@ -56,5 +56,34 @@ pub fn App() -> impl IntoView {
             ]
             tooltip=Tooltip::left_cursor().show_x_ticks(false)
         />
+        <h2>"Rendered to an SVG string"</h2>
+        <img src="/chart.svg" alt="Chart rendered on the server" />
     }
 }
+
+/// Renders a chart to an SVG string without a browser e.g., for emailed reports. Served at `/chart.svg`.
+#[cfg(feature = "ssr")]
+pub fn chart_svg() -> String {
+    render_svg_string(600.0, 300.0, || {
+        let series = Series::new(|data: &MyData| data.x)
+            .line(Line::new(|data: &MyData| data.y1).with_name("y1"))
+            .line(Line::new(|data: &MyData| data.y2).with_name("y2"));
+        view! {
+            <Chart
+                aspect_ratio=AspectRatio::from_env()
+                series=series
+                data=load_data()
+
+                top=RotatedLabel::middle("Hello, SVG!")
+                left=TickLabels::aligned_floats()
+                bottom=Legend::end()
+                inner=[
+                    AxisMarker::left_edge().into_inner(),
+                    AxisMarker::bottom_edge().into_inner(),
+                    XGridLine::default().into_inner(),
+                    YGridLine::default().into_inner(),
+                ]
+            />
+        }
+    })
+}
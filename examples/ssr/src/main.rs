@@ -1,10 +1,13 @@
 #[cfg(feature = "ssr")]
 #[tokio::main]
 async fn main() {
-    use axum::Router;
+    use axum::{http::header, routing::get, Router};
     use leptos::*;
     use leptos_axum::{generate_route_list, LeptosRoutes};
-    use my_example_ssr::{app::App, fallback::file_and_error_handler};
+    use my_example_ssr::{
+        app::{chart_svg, App},
+        fallback::file_and_error_handler,
+    };
 
     let conf = get_configuration(None).await.unwrap();
     let addr = conf.leptos_options.site_addr;
@@ -12,6 +15,10 @@ async fn main() {
 
     let routes = generate_route_list(App);
     let app = Router::new()
+        .route(
+            "/chart.svg",
+            get(|| async { ([(header::CONTENT_TYPE, "image/svg+xml")], chart_svg()) }),
+        )
         .leptos_routes(&leptos_options, routes, || view! { <App/> })
         .fallback(file_and_error_handler)
         .with_state(leptos_options);
@@ -10,6 +10,7 @@ pub struct Bounds {
 }
 
 impl Bounds {
+    /// Creates a new bounds with the top-left corner at (0, 0).
     pub fn new(width: f64, height: f64) -> Self {
         Self {
             left: 0.0,
@@ -50,34 +51,42 @@ impl Bounds {
         Self::from_points(left, top, right, bottom)
     }
 
+    /// Left edge X coord.
     pub fn left_x(&self) -> f64 {
         self.left
     }
 
+    /// Right edge X coord.
     pub fn right_x(&self) -> f64 {
         self.right
     }
 
+    /// Top edge Y coord.
     pub fn top_y(&self) -> f64 {
         self.top
     }
 
+    /// Bottom edge Y coord.
     pub fn bottom_y(&self) -> f64 {
         self.bottom
     }
 
+    /// Middle X coord.
     pub fn centre_x(&self) -> f64 {
         self.left_x() + (self.width() / 2.0)
     }
 
+    /// Middle Y coord.
     pub fn centre_y(&self) -> f64 {
         self.top_y() + (self.height() / 2.0)
     }
 
+    /// Width of the bounds.
     pub fn width(&self) -> f64 {
         self.right - self.left
     }
 
+    /// Height of the bounds.
     pub fn height(&self) -> f64 {
         self.bottom - self.top
     }
@@ -86,6 +95,14 @@ impl Bounds {
     pub fn contains(&self, x: f64, y: f64) -> bool {
         x >= self.left && x <= self.right && y >= self.top && y <= self.bottom
     }
+
+    /// Tests if the given bounds overlap. Touching edges do not overlap.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.left < other.right
+            && other.left < self.right
+            && self.top < other.bottom
+            && other.top < self.bottom
+    }
 }
 
 impl From<web_sys::DomRectReadOnly> for Bounds {
@@ -114,6 +131,19 @@ mod tests {
         assert!(!b.contains(5.5, 6.6),);
     }
 
+    #[test]
+    fn test_overlaps() {
+        let b = Bounds::from_points(0.0, 0.0, 10.0, 10.0);
+        assert!(b.overlaps(&b));
+        assert!(b.overlaps(&Bounds::from_points(5.0, 5.0, 15.0, 15.0)));
+        assert!(b.overlaps(&Bounds::from_points(2.0, 2.0, 4.0, 4.0)));
+        // Touching
+        assert!(!b.overlaps(&Bounds::from_points(10.0, 0.0, 20.0, 10.0)));
+        assert!(!b.overlaps(&Bounds::from_points(0.0, 10.0, 10.0, 20.0)));
+        // Apart
+        assert!(!b.overlaps(&Bounds::from_points(20.0, 20.0, 30.0, 30.0)));
+    }
+
     #[test]
     fn test_shrink() {
         let b = Bounds::new(100.0, 200.0);
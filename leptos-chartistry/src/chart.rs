@@ -7,10 +7,12 @@ use crate::{
     projection::Projection,
     series::{RenderData, UseData},
     state::{PreState, State},
+    svg_string::FixedSize,
     use_watched_node::{use_watched_node, UseWatchedNode},
-    AspectRatio, Padding, Series, Tick,
+    AspectRatio, DataPoint, Padding, Series, SyncGroup, Tick,
 };
 use leptos::{html::Div, *};
+use leptos_use::use_event_listener;
 
 pub const FONT_HEIGHT: f64 = 16.0;
 pub const FONT_WIDTH: f64 = 10.0;
@@ -165,15 +167,38 @@ pub fn Chart<T: 'static, X: Tick, Y: Tick>(
     #[prop(into, optional)]
     tooltip: Tooltip<X, Y>,
 
+    /// Highlights the series nearest the mouse on hover: lines are drawn thicker, other series are dimmed and the matching legend and tooltip rows are bold. Default is false.
+    #[prop(into, optional)]
+    highlight: MaybeSignal<bool>,
+
+    /// Shares hover with other charts in the same [SyncGroup]. Falls back to a [SyncGroup] provided as context. Default is none.
+    #[prop(into, optional)]
+    sync: Option<SyncGroup>,
+
+    /// Called when the inner chart area is clicked. Receives the [data](DataPoint) nearest to the mouse e.g., to navigate to a drill-down page. Default is none.
+    #[prop(into, optional)]
+    on_click: Option<Callback<DataPoint<X, Y>>>,
+    /// Called when the [data](DataPoint) nearest to the mouse changes. Receives `None` when the mouse leaves the inner chart area. Default is none.
+    #[prop(into, optional)]
+    on_hover: Option<Callback<Option<DataPoint<X, Y>>>>,
+
     /// Series to render. Maps `T` to lines, bars, etc. See [Series] for details.
     #[prop(into)]
     series: Series<T, X, Y>,
-    /// Data to render. Must be sorted.
+    /// Data to render. Lines (and stacked areas) join points in the order given so need data sorted by X. Scatters and bars can be unsorted: the tooltip still finds the nearest X.
     #[prop(into)]
     data: Signal<Vec<T>>,
 ) -> impl IntoView {
     let root = create_node_ref::<Div>();
-    let watch = use_watched_node(root);
+    // Rendering without a DOM (e.g., to an SVG string) uses a fixed size
+    let fixed_size = use_context::<FixedSize>();
+    let (watch, aspect_ratio) = match fixed_size {
+        Some(FixedSize(bounds)) => (
+            UseWatchedNode::fixed(root, bounds),
+            AspectRatio::from_outer_ratio(bounds.width(), bounds.height()).into(),
+        ),
+        None => (use_watched_node(root), aspect_ratio),
+    };
 
     // Aspect ratio signal
     let have_dimensions = create_memo(move |_| watch.bounds.get().is_some());
@@ -189,6 +214,7 @@ pub fn Chart<T: 'static, X: Tick, Y: Tick>(
     };
 
     let debug = create_memo(move |_| debug.get());
+    let highlight = create_memo(move |_| highlight.get());
     let font_height = create_memo(move |_| font_height.map(|f| f.get()).unwrap_or(FONT_HEIGHT));
     let font_width = create_memo(move |_| font_width.map(|f| f.get()).unwrap_or(FONT_WIDTH));
     let padding = create_memo(move |_| {
@@ -203,10 +229,50 @@ pub fn Chart<T: 'static, X: Tick, Y: Tick>(
     top.reverse();
     left.reverse();
 
-    // Build data
-    let data = UseData::new(series, data);
-    let pre = PreState::new(debug.into(), font_height, font_width, padding.into(), data);
+    let sync = sync.or_else(use_context::<SyncGroup>);
+
+    // Build data. Inner layouts (e.g., reference lines) can extend the ranges
+    let extend_x = {
+        let inner = inner.clone();
+        Signal::derive(move || inner.iter().flat_map(|inner| inner.extend_x()).collect())
+    };
+    let extend_y = {
+        let inner = inner.clone();
+        Signal::derive(move || inner.iter().flat_map(|inner| inner.extend_y()).collect())
+    };
+    let data = UseData::new(series, data, extend_x, extend_y);
+    let pre = PreState::new(
+        debug.into(),
+        highlight.into(),
+        font_height,
+        font_width,
+        padding.into(),
+        data,
+    );
+
+    let render = move || {
+        view! {
+            <RenderChart
+                watch=watch.clone()
+                pre_state=pre.clone()
+                aspect_ratio=calc
+                top=top.as_slice()
+                right=right.as_slice()
+                bottom=bottom.as_slice()
+                left=left.as_slice()
+                inner=inner.clone()
+                tooltip=tooltip.clone()
+                sync=sync
+                on_click=on_click
+                on_hover=on_hover
+                standalone=fixed_size.is_some()
+            />
+        }
+    };
 
+    if fixed_size.is_some() {
+        return render().into_view();
+    }
     view! {
         <div
             node_ref=root
@@ -216,20 +282,11 @@ pub fn Chart<T: 'static, X: Tick, Y: Tick>(
             style="overflow: visible;">
             <DebugRect label="Chart" debug=debug />
             <Show when=move || have_dimensions.get() fallback=|| view!(<p>"Loading..."</p>)>
-                <RenderChart
-                    watch=watch.clone()
-                    pre_state=pre.clone()
-                    aspect_ratio=calc
-                    top=top.as_slice()
-                    right=right.as_slice()
-                    bottom=bottom.as_slice()
-                    left=left.as_slice()
-                    inner=inner.clone()
-                    tooltip=tooltip.clone()
-                />
+                {render.clone()}
             </Show>
         </div>
     }
+    .into_view()
 }
 
 #[component]
@@ -243,6 +300,10 @@ fn RenderChart<'a, X: Tick, Y: Tick>(
     left: &'a [EdgeLayout<Y>],
     inner: Vec<InnerLayout<X, Y>>,
     tooltip: Tooltip<X, Y>,
+    sync: Option<SyncGroup>,
+    on_click: Option<Callback<DataPoint<X, Y>>>,
+    on_hover: Option<Callback<Option<DataPoint<X, Y>>>>,
+    standalone: bool,
 ) -> impl IntoView {
     let debug = pre_state.debug;
 
@@ -250,23 +311,76 @@ fn RenderChart<'a, X: Tick, Y: Tick>(
     let (layout, edges) = Layout::compose(top, right, bottom, left, aspect_ratio, &pre_state);
 
     // Finalise state
-    let projection = {
+    let mk_projection = {
         let range_x = pre_state.data.range_x;
-        let range_y = pre_state.data.range_y;
+        let scale_x = pre_state.data.scale_x;
+        let scale_y = pre_state.data.scale_y;
         let includes_bars = pre_state.data.includes_bars;
-        create_memo(move |_| {
-            let mut inner = layout.inner.get();
-            // If we include bars, shrink the sides by half the width of X
-            if includes_bars.get() {
-                let half = layout.x_width.get() / 2.0;
-                inner = inner.shrink(0.0, half, 0.0, half);
-            }
+        let includes_horizontal_bars = pre_state.data.includes_horizontal_bars;
+        move |range_y: Signal<Option<(f64, f64)>>| -> Signal<Projection> {
+            create_memo(move |_| {
+                let mut inner = layout.inner.get();
+                // If we include bars, shrink the sides by half the width of X
+                if includes_bars.get() {
+                    let half = layout.x_width.get() / 2.0;
+                    inner = inner.shrink(0.0, half, 0.0, half);
+                }
+                // Likewise for horizontal bars, shrink the top and bottom by half the height of Y
+                if includes_horizontal_bars.get() {
+                    let half = layout.y_width.get() / 2.0;
+                    inner = inner.shrink(half, 0.0, half, 0.0);
+                }
 
-            Projection::new(inner, range_x.get().positions(), range_y.get().positions())
-        })
-        .into()
+                Projection::scaled(
+                    inner,
+                    range_x.get().positions_for(scale_x.get()),
+                    range_y.get(),
+                    scale_x.get(),
+                    scale_y.get(),
+                )
+            })
+            .into()
+        }
+    };
+    let projection = {
+        let range_y = pre_state.data.range_y;
+        let scale_y = pre_state.data.scale_y;
+        mk_projection(Signal::derive(move || {
+            range_y.get().positions_for(scale_y.get())
+        }))
+    };
+    // Secondary Y axis shares X
+    let projection_y2 = {
+        let range_y2 = pre_state.data.range_y2;
+        let scale_y = pre_state.data.scale_y;
+        mk_projection(Signal::derive(move || {
+            range_y2.get().positions_for(scale_y.get())
+        }))
     };
-    let state = State::new(pre_state, &watch, layout, projection);
+    let state = State::new(pre_state, &watch, layout, projection, projection_y2, sync);
+
+    // Data events
+    let hover_inner = state.hover_inner;
+    let nearest = state.pre.data.nearest_data_point(state.hover_position_x);
+    if let Some(on_click) = on_click {
+        _ = use_event_listener(state.root, ev::click, move |_| {
+            if hover_inner.get_untracked() {
+                if let Some(point) = nearest.get_untracked() {
+                    on_click.call(point);
+                }
+            }
+        });
+    }
+    if let Some(on_hover) = on_hover {
+        let hovered = create_memo(move |_| hover_inner.get().then(|| nearest.get()).flatten());
+        // Skip the first run: nothing is hovered yet
+        create_effect(move |prev: Option<()>| {
+            let point = hovered.get();
+            if prev.is_some() {
+                on_hover.call(point);
+            }
+        });
+    }
 
     // Render edges
     let edges = edges
@@ -283,6 +397,7 @@ fn RenderChart<'a, X: Tick, Y: Tick>(
     let outer = state.layout.outer;
     view! {
         <svg
+            xmlns=standalone.then_some("http://www.w3.org/2000/svg")
             width=move || format!("{}px", outer.get().width())
             height=move || format!("{}px", outer.get().height())
             viewBox=move || with!(|outer| format!("0 0 {} {}", outer.width(), outer.height()))
@@ -293,7 +408,8 @@ fn RenderChart<'a, X: Tick, Y: Tick>(
             {edges}
             <RenderData state=state.clone() />
         </svg>
-        <Tooltip tooltip=tooltip state=state />
+        // No tooltip without a DOM
+        {(!standalone).then(|| view!(<Tooltip tooltip=tooltip state=state />))}
     }
 }
 
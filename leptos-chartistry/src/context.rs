@@ -0,0 +1,154 @@
+use crate::{bounds::Bounds, edge::Edge, state::State, Tick};
+use leptos::*;
+
+/// A read-only view of a chart passed to [CustomInner](crate::CustomInner).
+///
+/// Getters are reactive: call them inside a closure (e.g., in a `view!`) to follow changes such as resizes, zooms and hovers. Coordinates are in SVG space where the top-left of the chart is (0, 0).
+#[derive(Clone)]
+pub struct ChartContext<X: 'static, Y: 'static> {
+    state: State<X, Y>,
+    hover_x: Memo<Option<X>>,
+}
+
+impl<X: Tick, Y: Tick> ChartContext<X, Y> {
+    pub(crate) fn new(state: &State<X, Y>) -> Self {
+        let nearest_x = state.pre.data.nearest_data_x(state.hover_position_x);
+        let hover_any = state.hover_any;
+        Self {
+            state: state.clone(),
+            hover_x: create_memo(move |_| hover_any.get().then(|| nearest_x.get()).flatten()),
+        }
+    }
+
+    /// Returns true if debug mode is enabled.
+    pub fn debug(&self) -> bool {
+        self.state.pre.debug.get()
+    }
+
+    /// Returns the font height. See [Chart](crate::Chart) for details.
+    pub fn font_height(&self) -> f64 {
+        self.state.pre.font_height.get()
+    }
+
+    /// Returns the font width. See [Chart](crate::Chart) for details.
+    pub fn font_width(&self) -> f64 {
+        self.state.pre.font_width.get()
+    }
+
+    /// Returns the bounds of the whole chart.
+    pub fn outer_bounds(&self) -> Bounds {
+        self.state.layout.outer.get()
+    }
+
+    /// Returns the bounds of the inner chart area (where series are drawn).
+    pub fn inner_bounds(&self) -> Bounds {
+        self.state.layout.inner.get()
+    }
+
+    /// Returns the X range of the chart. None if there's no data.
+    pub fn range_x(&self) -> Option<(X, X)> {
+        self.state.pre.data.range_x.with(|range| {
+            range
+                .range()
+                .map(|(first, last)| (first.clone(), last.clone()))
+        })
+    }
+
+    /// Returns the Y range of the chart. None if there's no data.
+    pub fn range_y(&self) -> Option<(Y, Y)> {
+        self.state.pre.data.range_y.with(|range| {
+            range
+                .range()
+                .map(|(first, last)| (first.clone(), last.clone()))
+        })
+    }
+
+    /// Returns the SVG coords of an X and Y value. May be outside the inner chart area or NaN (e.g., log of a negative).
+    pub fn to_svg(&self, x: &X, y: &Y) -> (f64, f64) {
+        self.state
+            .projection
+            .get()
+            .position_to_svg(x.position(), y.position())
+    }
+
+    /// Returns the SVG X coord of an X value.
+    pub fn to_svg_x(&self, x: &X) -> f64 {
+        let proj = self.state.projection.get();
+        proj.position_to_svg(x.position(), 0.0).0
+    }
+
+    /// Returns the SVG Y coord of a Y value.
+    pub fn to_svg_y(&self, y: &Y) -> f64 {
+        let proj = self.state.projection.get();
+        proj.position_to_svg(0.0, y.position()).1
+    }
+
+    /// Returns the mouse position (SVG coords) if it's over the inner chart area.
+    pub fn mouse(&self) -> Option<(f64, f64)> {
+        self.state
+            .hover_inner
+            .get()
+            .then(|| self.state.mouse_chart.get())
+    }
+
+    /// Returns the X value of the data nearest to the mouse. Follows the [sync group](crate::SyncGroup). None if not hovering.
+    pub fn hover_x(&self) -> Option<X> {
+        self.hover_x.get()
+    }
+}
+
+/// A read-only view of an edge component's place in the chart. Passed to [CustomEdge](crate::CustomEdge).
+///
+/// Getters are reactive: call them inside a closure to follow changes such as resizes.
+#[derive(Copy, Clone, Debug)]
+pub struct EdgeContext {
+    edge: Edge,
+    bounds: Memo<Bounds>,
+    inner: Memo<Bounds>,
+    debug: Signal<bool>,
+    font_height: Memo<f64>,
+    font_width: Memo<f64>,
+}
+
+impl EdgeContext {
+    pub(crate) fn new<X, Y>(edge: Edge, bounds: Memo<Bounds>, state: &State<X, Y>) -> Self {
+        Self {
+            edge,
+            bounds,
+            inner: state.layout.inner,
+            debug: state.pre.debug,
+            font_height: state.pre.font_height,
+            font_width: state.pre.font_width,
+        }
+    }
+
+    /// Returns the edge the component is placed on.
+    pub fn edge(&self) -> Edge {
+        self.edge
+    }
+
+    /// Returns the bounds given to the component.
+    pub fn bounds(&self) -> Bounds {
+        self.bounds.get()
+    }
+
+    /// Returns the bounds of the inner chart area (where series are drawn).
+    pub fn inner_bounds(&self) -> Bounds {
+        self.inner.get()
+    }
+
+    /// Returns true if debug mode is enabled.
+    pub fn debug(&self) -> bool {
+        self.debug.get()
+    }
+
+    /// Returns the font height. See [Chart](crate::Chart) for details.
+    pub fn font_height(&self) -> f64 {
+        self.font_height.get()
+    }
+
+    /// Returns the font width. See [Chart](crate::Chart) for details.
+    pub fn font_width(&self) -> f64 {
+        self.font_width.get()
+    }
+}
@@ -0,0 +1,285 @@
+use crate::{ticks::BinEdges, Bar, Colour, Series, TickLabels};
+use leptos::*;
+
+/// Maximum number of bins. Guards against a tiny bin width.
+const MAX_BINS: usize = 10_000;
+
+/// Builds a histogram from raw samples. Samples are sorted into equal width bins (see [Binning]) and charted as contiguous bars with ticks on the edges of each bin.
+///
+/// # Example
+/// ```rust
+/// # use leptos::*;
+/// # use leptos_chartistry::*;
+/// # fn example(latencies: Signal<Vec<f64>>) -> impl IntoView {
+/// let histogram = Histogram::new(Binning::FreedmanDiaconis).with_name("requests");
+/// let bins = histogram.bins(latencies);
+/// view! {
+///     <Chart
+///         aspect_ratio=AspectRatio::from_outer_height(300.0, 1.2)
+///         series=histogram.series()
+///         data=bins
+///         left=TickLabels::aligned_floats()
+///         bottom=histogram.tick_labels(bins)
+///     />
+/// }
+/// # }
+/// ```
+/// See this in action with the [histogram example](https://feral-dot-io.github.io/leptos-chartistry/examples.html#histogram).
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct Histogram {
+    /// How samples are sorted into bins. Default is [Binning::FreedmanDiaconis].
+    pub binning: RwSignal<Binning>,
+    /// Name of the bars. Used in the legend and tooltip.
+    pub name: RwSignal<String>,
+    /// Colour of the bars. If not set, the first colour in the series will be used.
+    pub colour: RwSignal<Option<Colour>>,
+}
+
+/// How a [Histogram] sorts samples into equal width bins.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub enum Binning {
+    /// A fixed number of bins spanning the smallest to largest sample.
+    Count(usize),
+    /// Bins of a fixed width starting from the smallest sample. Widths that aren't positive result in a single bin.
+    Width(f64),
+    /// Picks a width from the spread of the samples: twice the interquartile range divided by the cube root of the number of samples.[^FD] Robust to outliers. Falls back to Sturges' rule (log2 of the number of samples plus one bins) when the interquartile range is zero.
+    ///
+    /// [^FD]: Freedman, D., Diaconis, P., “On the histogram as a density estimator: L2 theory.”, Zeitschrift für Wahrscheinlichkeitstheorie und Verwandte Gebiete, vol. 57, pp. 453–476, 1981.
+    #[default]
+    FreedmanDiaconis,
+}
+
+/// A single histogram bin: the number of samples from `start` (inclusive) to `end` (exclusive). The last bin includes its end.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct Bin {
+    /// Start edge of the bin.
+    pub start: f64,
+    /// End edge of the bin.
+    pub end: f64,
+    /// Number of samples in the bin.
+    pub count: usize,
+}
+
+impl Default for Histogram {
+    fn default() -> Self {
+        Self::new(Binning::default())
+    }
+}
+
+impl Histogram {
+    /// Create a new histogram with the given binning.
+    pub fn new(binning: impl Into<Binning>) -> Self {
+        Self {
+            binning: create_rw_signal(binning.into()),
+            name: RwSignal::default(),
+            colour: RwSignal::default(),
+        }
+    }
+
+    /// Set the name of the bars. Used in the legend and tooltip.
+    pub fn with_name(self, name: impl Into<String>) -> Self {
+        self.name.set(name.into());
+        self
+    }
+
+    /// Set the colour of the bars. If not set, the first colour in the series will be used.
+    pub fn with_colour(self, colour: impl Into<Option<Colour>>) -> Self {
+        self.colour.set(colour.into());
+        self
+    }
+
+    /// Sorts samples into bins. Pass the result to the chart's `data`. Missing (NaN) and infinite samples are skipped.
+    pub fn bins(&self, samples: impl Into<Signal<Vec<f64>>>) -> Signal<Vec<Bin>> {
+        let (binning, samples) = (self.binning, samples.into());
+        create_memo(move |_| samples.with(|samples| binning.get().bins(samples))).into()
+    }
+
+    /// Returns a series of contiguous bars (no gap) for the bins. Each bar is placed at the centre of its bin with a height of its count. The Y axis starts from zero.
+    pub fn series(&self) -> Series<Bin, f64, f64> {
+        let mut bar = Bar::new(|bin: &Bin| bin.count as f64)
+            .with_gap(0.0)
+            .with_group_gap(0.0);
+        bar.name = self.name;
+        bar.colour = self.colour;
+        Series::new(|bin: &Bin| bin.centre())
+            .bar(bar)
+            .with_min_y(0.0)
+    }
+
+    /// Returns tick labels on the edges of the given bins. Use on the bottom (or top) edge.
+    pub fn tick_labels(&self, bins: Signal<Vec<Bin>>) -> TickLabels<f64> {
+        let edges = Signal::derive(move || bins.with(|bins| Bin::edges(bins)));
+        TickLabels::from_generator(BinEdges::new(edges))
+    }
+}
+
+impl Binning {
+    /// Sorts samples into bins. Missing (NaN) and infinite samples are skipped. No samples result in no bins. If all samples are the same, a single bin of width 1.0 is centred on them.
+    pub fn bins(&self, samples: &[f64]) -> Vec<Bin> {
+        let mut sorted = samples
+            .iter()
+            .copied()
+            .filter(|sample| sample.is_finite())
+            .collect::<Vec<_>>();
+        sorted.sort_by(f64::total_cmp);
+        let (Some(&min), Some(&max)) = (sorted.first(), sorted.last()) else {
+            return Vec::new();
+        };
+        if min == max {
+            return vec![Bin::new(min - 0.5, max + 0.5, sorted.len())];
+        }
+
+        let (count, width) = self.count_width(&sorted);
+        let mut counts = vec![0; count];
+        for sample in sorted {
+            let index = ((sample - min) / width).floor() as usize;
+            counts[index.min(count - 1)] += 1;
+        }
+        counts
+            .into_iter()
+            .enumerate()
+            .map(|(index, n)| {
+                let start = min + width * index as f64;
+                Bin::new(start, start + width, n)
+            })
+            .collect()
+    }
+
+    /// Returns the number of bins and their width for sorted samples with a non-zero range.
+    fn count_width(&self, sorted: &[f64]) -> (usize, f64) {
+        let range = sorted[sorted.len() - 1] - sorted[0];
+        let count_of = |width: f64| ((range / width) - 1e-9).ceil().max(1.0) as usize;
+        let by_count = |count: usize| {
+            let count = count.clamp(1, MAX_BINS);
+            (count, range / count as f64)
+        };
+        match *self {
+            Binning::Count(count) => by_count(count),
+            Binning::Width(width) if width.is_finite() && width > 0.0 => {
+                let count = count_of(width);
+                if count > MAX_BINS {
+                    by_count(MAX_BINS)
+                } else {
+                    (count, width)
+                }
+            }
+            Binning::Width(_) => by_count(1),
+            Binning::FreedmanDiaconis => {
+                let n = sorted.len() as f64;
+                let iqr = quantile(sorted, 0.75) - quantile(sorted, 0.25);
+                let width = 2.0 * iqr / n.cbrt();
+                if width > 0.0 {
+                    by_count(count_of(width))
+                } else {
+                    // Sturges' rule
+                    by_count(n.log2().ceil() as usize + 1)
+                }
+            }
+        }
+    }
+}
+
+impl From<usize> for Binning {
+    fn from(count: usize) -> Self {
+        Self::Count(count)
+    }
+}
+
+impl Bin {
+    fn new(start: f64, end: f64, count: usize) -> Self {
+        Self { start, end, count }
+    }
+
+    /// Returns the centre of the bin.
+    pub fn centre(&self) -> f64 {
+        (self.start + self.end) / 2.0
+    }
+
+    /// Returns the edges of contiguous bins in order.
+    fn edges(bins: &[Bin]) -> Vec<f64> {
+        bins.iter()
+            .map(|bin| bin.start)
+            .chain(bins.last().map(|bin| bin.end))
+            .collect()
+    }
+}
+
+/// Returns the q-th quantile of sorted samples. Linearly interpolated between samples.
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let position = q * (sorted.len() - 1) as f64;
+    let (lower, upper) = (position.floor() as usize, position.ceil() as usize);
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (position - lower as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn counts(bins: &[Bin]) -> Vec<usize> {
+        bins.iter().map(|bin| bin.count).collect()
+    }
+
+    #[test]
+    fn test_count() {
+        let bins = Binning::Count(2).bins(&[0.0, 1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(bins, vec![Bin::new(0.0, 2.0, 2), Bin::new(2.0, 4.0, 3)]);
+        assert_eq!(Bin::edges(&bins), vec![0.0, 2.0, 4.0]);
+        assert_eq!(bins[1].centre(), 3.0);
+        // At least one bin
+        assert_eq!(counts(&Binning::Count(0).bins(&[0.0, 1.0])), vec![2]);
+    }
+
+    #[test]
+    fn test_width() {
+        let bins = Binning::Width(1.5).bins(&[1.0, 2.0, 2.5, 4.0, 5.0]);
+        assert_eq!(Bin::edges(&bins), vec![1.0, 2.5, 4.0, 5.5]);
+        assert_eq!(counts(&bins), vec![2, 1, 2]);
+        // Exact fit: the largest sample is in the last bin
+        let bins = Binning::Width(2.0).bins(&[0.0, 3.0, 4.0]);
+        assert_eq!(Bin::edges(&bins), vec![0.0, 2.0, 4.0]);
+        assert_eq!(counts(&bins), vec![1, 2]);
+        // Invalid
+        assert_eq!(counts(&Binning::Width(0.0).bins(&[0.0, 3.0])), vec![2]);
+        assert_eq!(counts(&Binning::Width(f64::NAN).bins(&[0.0, 3.0])), vec![2]);
+        // Limited
+        assert_eq!(Binning::Width(1e-9).bins(&[0.0, 1.0]).len(), MAX_BINS);
+    }
+
+    #[test]
+    fn test_freedman_diaconis() {
+        // IQR of 0..=7 is 3.5, width = 2 * 3.5 / 2 = 3.5
+        let samples = (0..8).map(|i| i as f64).collect::<Vec<_>>();
+        let bins = Binning::FreedmanDiaconis.bins(&samples);
+        assert_eq!(Bin::edges(&bins), vec![0.0, 3.5, 7.0]);
+        assert_eq!(counts(&bins), vec![4, 4]);
+        // Zero IQR falls back to Sturges: log2(8) + 1 = 4 bins
+        let samples = [0.0, 5.0, 5.0, 5.0, 5.0, 5.0, 5.0, 8.0];
+        assert_eq!(Binning::FreedmanDiaconis.bins(&samples).len(), 4);
+    }
+
+    #[test]
+    fn test_bins_edge_cases() {
+        assert!(Binning::default().bins(&[]).is_empty());
+        assert!(Binning::default().bins(&[f64::NAN]).is_empty());
+        // Skips missing
+        let bins = Binning::Count(1).bins(&[1.0, f64::NAN, 3.0, f64::INFINITY]);
+        assert_eq!(bins, vec![Bin::new(1.0, 3.0, 2)]);
+        // Same samples
+        assert_eq!(
+            Binning::Count(4).bins(&[2.0, 2.0]),
+            vec![Bin::new(1.5, 2.5, 2)]
+        );
+    }
+
+    #[test]
+    fn test_quantile() {
+        let sorted = [1.0, 2.0, 3.0, 4.0];
+        assert_eq!(quantile(&sorted, 0.0), 1.0);
+        assert_eq!(quantile(&sorted, 0.5), 2.5);
+        assert_eq!(quantile(&sorted, 0.25), 1.75);
+        assert_eq!(quantile(&sorted, 1.0), 4.0);
+    }
+}
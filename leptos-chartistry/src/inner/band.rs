@@ -0,0 +1,193 @@
+use super::UseInner;
+use crate::{bounds::Bounds, colours::Colour, debug::DebugRect, state::State, Tick};
+use leptos::*;
+use std::rc::Rc;
+
+/// Default colour for bands.
+pub const BAND_COLOUR: Colour = Colour::from_rgb(0x9A, 0x9A, 0x9A);
+
+/// Default opacity for bands.
+pub const BAND_OPACITY: f64 = 0.2;
+
+macro_rules! impl_band {
+    ($name:ident, $t:ident, $axis:literal) => {
+        #[doc = concat!("Builds a shaded band between two ", $axis, " values e.g., an incident window or target range. Drawn beneath the series and clipped to the inner chart area. Does not extend the axis range.")]
+        #[derive(Clone, Debug, PartialEq)]
+        pub struct $name<$t: 'static> {
+            #[doc = concat!("Start ", $axis, " value of the band.")]
+            pub start: RwSignal<$t>,
+            #[doc = concat!("End ", $axis, " value of the band.")]
+            pub end: RwSignal<$t>,
+            /// Label shown inside the band. Empty for no label.
+            pub label: RwSignal<String>,
+            /// Colour of the band and label.
+            pub colour: RwSignal<Colour>,
+            /// Opacity of the band fill. Clamped to 0.0 (transparent) to 1.0 (opaque). Default is [BAND_OPACITY].
+            pub opacity: RwSignal<f64>,
+        }
+
+        impl<$t: Tick> $name<$t> {
+            #[doc = concat!("Creates a new band between two ", $axis, " values.")]
+            pub fn new(start: impl Into<$t>, end: impl Into<$t>) -> Self {
+                Self {
+                    start: create_rw_signal(start.into()),
+                    end: create_rw_signal(end.into()),
+                    label: RwSignal::default(),
+                    colour: create_rw_signal(BAND_COLOUR),
+                    opacity: create_rw_signal(BAND_OPACITY),
+                }
+            }
+
+            /// Sets the label shown inside the band.
+            pub fn with_label(self, label: impl Into<String>) -> Self {
+                self.label.set(label.into());
+                self
+            }
+
+            /// Sets the colour of the band and label.
+            pub fn with_colour(self, colour: impl Into<Colour>) -> Self {
+                self.colour.set(colour.into());
+                self
+            }
+
+            /// Sets the opacity of the band fill.
+            pub fn with_opacity(self, opacity: impl Into<f64>) -> Self {
+                self.opacity.set(opacity.into());
+                self
+            }
+        }
+    };
+}
+
+impl_band!(XBand, X, "X");
+impl_band!(YBand, Y, "Y");
+
+impl<X: Tick, Y: Tick> UseInner<X, Y> for XBand<X> {
+    fn render(self: Rc<Self>, state: State<X, Y>) -> View {
+        let band = (*self).clone();
+        let proj = state.projection;
+        let inner = state.layout.inner;
+        // Spans the height of the inner chart area
+        let bounds = Signal::derive(move || {
+            let proj = proj.get();
+            let (x1, _) = proj.position_to_svg(band.start.get().position(), 0.0);
+            let (x2, _) = proj.position_to_svg(band.end.get().position(), 0.0);
+            let inner = inner.get();
+            clip(inner, x1, inner.top_y(), x2, inner.bottom_y())
+        });
+        view! {
+            <Band
+                id="x"
+                label=band.label
+                colour=band.colour
+                opacity=band.opacity
+                bounds=bounds
+                state=state />
+        }
+    }
+}
+
+impl<X: Tick, Y: Tick> UseInner<X, Y> for YBand<Y> {
+    fn render(self: Rc<Self>, state: State<X, Y>) -> View {
+        let band = (*self).clone();
+        let proj = state.projection;
+        let inner = state.layout.inner;
+        // Spans the width of the inner chart area
+        let bounds = Signal::derive(move || {
+            let proj = proj.get();
+            let (_, y1) = proj.position_to_svg(0.0, band.start.get().position());
+            let (_, y2) = proj.position_to_svg(0.0, band.end.get().position());
+            let inner = inner.get();
+            clip(inner, inner.left_x(), y1, inner.right_x(), y2)
+        });
+        view! {
+            <Band
+                id="y"
+                label=band.label
+                colour=band.colour
+                opacity=band.opacity
+                bounds=bounds
+                state=state />
+        }
+    }
+}
+
+/// Clips the rectangle between two points to the inner chart area. None if outside or missing (NaN).
+fn clip(inner: Bounds, x1: f64, y1: f64, x2: f64, y2: f64) -> Option<Bounds> {
+    if [x1, y1, x2, y2].iter().any(|c| c.is_nan()) {
+        return None;
+    }
+    let (left, right) = (
+        x1.min(x2).max(inner.left_x()),
+        x1.max(x2).min(inner.right_x()),
+    );
+    let (top, bottom) = (
+        y1.min(y2).max(inner.top_y()),
+        y1.max(y2).min(inner.bottom_y()),
+    );
+    (left < right && top < bottom).then(|| Bounds::from_points(left, top, right, bottom))
+}
+
+#[component]
+fn Band<X: 'static, Y: 'static>(
+    id: &'static str,
+    label: RwSignal<String>,
+    colour: RwSignal<Colour>,
+    opacity: RwSignal<f64>,
+    bounds: Signal<Option<Bounds>>,
+    state: State<X, Y>,
+) -> impl IntoView {
+    let debug = state.pre.debug;
+    let font_height = state.pre.font_height;
+    let font_width = state.pre.font_width;
+    let colour = move || colour.get().to_string();
+
+    view! {
+        <g class=format!("_chartistry_{}_band", id)>
+            {move || bounds.get().map(|bounds| view! {
+                <DebugRect label=format!("{}_band", id) debug=debug />
+                <rect
+                    x=bounds.left_x()
+                    y=bounds.top_y()
+                    width=bounds.width()
+                    height=bounds.height()
+                    fill=colour
+                    fill-opacity=move || opacity.get().clamp(0.0, 1.0) />
+                // Top-left corner, inside the band
+                <text
+                    x=move || bounds.left_x() + font_width.get() / 2.0
+                    y=move || bounds.top_y() + font_height.get() / 4.0
+                    fill=colour
+                    style="white-space: pre;"
+                    font-size=move || font_height.get()
+                    dominant-baseline="hanging">
+                    {label}
+                </text>
+            })}
+        </g>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clip() {
+        let inner = Bounds::from_points(10.0, 10.0, 100.0, 50.0);
+        // Inside, either order
+        assert_eq!(
+            clip(inner, 40.0, 10.0, 20.0, 50.0),
+            Some(Bounds::from_points(20.0, 10.0, 40.0, 50.0))
+        );
+        // Partially outside
+        assert_eq!(
+            clip(inner, 0.0, 10.0, 20.0, 50.0),
+            Some(Bounds::from_points(10.0, 10.0, 20.0, 50.0))
+        );
+        // Outside
+        assert_eq!(clip(inner, 110.0, 10.0, 120.0, 50.0), None);
+        // Missing
+        assert_eq!(clip(inner, f64::NAN, 10.0, 20.0, 50.0), None);
+    }
+}
@@ -0,0 +1,142 @@
+use super::{
+    zoom::{ticks_at_positions, MIN_SELECTION},
+    UseInner,
+};
+use crate::{colours::Colour, debug::DebugRect, state::State, Tick};
+use leptos::*;
+use leptos_use::{use_event_listener, use_window};
+use std::rc::Rc;
+
+/// Default colour for the brush selection.
+pub const BRUSH_COLOUR: Colour = Colour::from_rgb(0x12, 0xA5, 0xED);
+
+/// Builds an X brush: drag across the inner chart area to select an X range.
+///
+/// The selection is drawn as a band and written to the [selection](Self::selection) signal. Use it to drive other parts of your page e.g., filtering a table or loading detail data. A click without dragging clears the selection. Requires `X` to support [Tick::at_position].
+///
+/// Avoid using with [Zoom](crate::Zoom) on the same chart as both respond to dragging.
+pub struct XBrush<X: 'static> {
+    /// The selected X range. `None` if nothing is selected. Can be set to change or clear the selection.
+    pub selection: RwSignal<Option<(X, X)>>,
+    /// Colour of the selection band.
+    pub colour: RwSignal<Colour>,
+}
+
+impl<X> Clone for XBrush<X> {
+    fn clone(&self) -> Self {
+        Self {
+            selection: self.selection,
+            colour: self.colour,
+        }
+    }
+}
+
+impl<X: Tick> XBrush<X> {
+    /// Creates a new brush that writes its selection to the given signal.
+    pub fn new(selection: RwSignal<Option<(X, X)>>) -> Self {
+        Self {
+            selection,
+            colour: create_rw_signal(BRUSH_COLOUR),
+        }
+    }
+
+    /// Sets the colour of the selection band.
+    pub fn with_colour(self, colour: impl Into<Colour>) -> Self {
+        self.colour.set(colour.into());
+        self
+    }
+}
+
+impl<X: Tick> Default for XBrush<X> {
+    fn default() -> Self {
+        Self::new(RwSignal::default())
+    }
+}
+
+impl<X: Tick, Y: Tick> UseInner<X, Y> for XBrush<X> {
+    fn render(self: Rc<Self>, state: State<X, Y>) -> View {
+        view!( <XBrush brush=(*self).clone() state=state /> )
+    }
+}
+
+#[component]
+fn XBrush<X: Tick, Y: Tick>(brush: XBrush<X>, state: State<X, Y>) -> impl IntoView {
+    let debug = state.pre.debug;
+    let range_x = state.pre.data.range_x;
+    let proj = state.projection;
+    let mouse = state.mouse_chart;
+    let hover_inner = state.hover_inner;
+    let inner = state.layout.inner;
+    let root = state.root;
+    let XBrush { selection, colour } = brush;
+
+    // Start dragging from an SVG X coord
+    let dragging = create_rw_signal::<Option<f64>>(None);
+    _ = use_event_listener(root, ev::mousedown, move |ev| {
+        if ev.button() != 0 || !hover_inner.get_untracked() {
+            return;
+        }
+        // Avoid selecting text
+        ev.prevent_default();
+        dragging.set(Some(mouse.get_untracked().0));
+    });
+
+    // SVG X coords of the band being dragged, clamped to the inner chart area
+    let dragged = create_memo(move |_| {
+        let start = dragging.get()?;
+        let (end, inner) = (mouse.get().0, inner.get());
+        let clamp = |x: f64| x.clamp(inner.left_x(), inner.right_x());
+        let (start, end) = (clamp(start), clamp(end));
+        Some((start.min(end), start.max(end)))
+    });
+
+    // Stop dragging. Listen on the window in case the mouse leaves the chart
+    _ = use_event_listener(use_window(), ev::mouseup, move |_| {
+        if let Some((left, right)) = dragged.get_untracked() {
+            let range = if right - left >= MIN_SELECTION {
+                let proj = proj.get_untracked();
+                let (left, _) = proj.svg_to_position(left, 0.0);
+                let (right, _) = proj.svg_to_position(right, 0.0);
+                ticks_at_positions(range_x, left, right)
+            } else {
+                // A click clears
+                None
+            };
+            selection.set(range);
+        }
+        dragging.set(None);
+    });
+
+    // Band to show: the drag in progress or the current selection
+    let band = create_memo(move |_| {
+        dragged.get().or_else(|| {
+            selection.with(|selection| {
+                let (first, last) = selection.as_ref()?;
+                let proj = proj.get();
+                let (first, _) = proj.position_to_svg(first.position(), 0.0);
+                let (last, _) = proj.position_to_svg(last.position(), 0.0);
+                Some((first.min(last), first.max(last)))
+            })
+        })
+    });
+
+    let colour = move || colour.get().to_string();
+    view! {
+        <g class="_chartistry_x_brush">
+            <DebugRect label="x_brush" debug=debug />
+            {move || band.get().map(|(left, right)| {
+                let inner = inner.get();
+                view! {
+                    <rect
+                        x=left
+                        y=inner.top_y()
+                        width=right - left
+                        height=inner.height()
+                        fill=colour
+                        fill-opacity=0.2
+                        stroke=colour />
+                }
+            })}
+        </g>
+    }
+}
@@ -0,0 +1,279 @@
+use super::UseInner;
+use crate::{bounds::Bounds, colours::Colour, debug::DebugRect, state::State, Tick};
+use leptos::*;
+use std::rc::Rc;
+
+/// Default colour for callouts.
+pub const CALLOUT_COLOUR: Colour = Colour::from_rgb(0x55, 0x55, 0x55);
+/// Default background colour of callout labels. Assumes a light background.
+pub const CALLOUT_BACKGROUND_COLOUR: Colour = Colour::from_rgb(0xFF, 0xFF, 0xFF);
+
+/// Builds callouts: a marker on a data point with a leader line to a label. Useful for marking events such as releases or anomalies.
+///
+/// Labels are placed above their point where possible, otherwise below. They're nudged so that they don't overlap each other or the edge of the inner chart area. Points outside the inner chart area (e.g., when zoomed) are not shown.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Callouts<X: 'static, Y: 'static> {
+    /// Points to call out: X, Y and label.
+    pub points: Signal<Vec<(X, Y, String)>>,
+    /// Colour of the markers, leader lines and labels.
+    pub colour: RwSignal<Colour>,
+    /// Background colour of the labels. Set to the same as the chart's background. Default is [CALLOUT_BACKGROUND_COLOUR].
+    pub background: RwSignal<Colour>,
+}
+
+impl<X: Tick, Y: Tick> Callouts<X, Y> {
+    /// Creates callouts from a list of points: X, Y and label.
+    pub fn new<S: Into<String>>(points: impl IntoIterator<Item = (X, Y, S)>) -> Self {
+        let points = points
+            .into_iter()
+            .map(|(x, y, label)| (x, y, label.into()))
+            .collect::<Vec<_>>();
+        Self::from_points(Signal::derive(move || points.clone()))
+    }
+
+    fn from_points(points: Signal<Vec<(X, Y, String)>>) -> Self {
+        Self {
+            points,
+            colour: create_rw_signal(CALLOUT_COLOUR),
+            background: create_rw_signal(CALLOUT_BACKGROUND_COLOUR),
+        }
+    }
+
+    /// Creates callouts from your data. Return `Some((x, y, label))` for each `T` to call out. Follows changes to the data.
+    pub fn from_data<T: 'static>(
+        data: Signal<Vec<T>>,
+        callout: impl Fn(&T) -> Option<(X, Y, String)> + 'static,
+    ) -> Self {
+        Self::from_points(Signal::derive(move || {
+            data.with(|data| data.iter().filter_map(&callout).collect())
+        }))
+    }
+
+    /// Sets the colour of the markers, leader lines and labels.
+    pub fn with_colour(self, colour: impl Into<Colour>) -> Self {
+        self.colour.set(colour.into());
+        self
+    }
+
+    /// Sets the background colour of the labels.
+    pub fn with_background(self, background: impl Into<Colour>) -> Self {
+        self.background.set(background.into());
+        self
+    }
+}
+
+impl<X: Tick, Y: Tick> Default for Callouts<X, Y> {
+    fn default() -> Self {
+        Self::new(Vec::<(X, Y, String)>::new())
+    }
+}
+
+impl<X: Tick, Y: Tick> UseInner<X, Y> for Callouts<X, Y> {
+    fn render(self: Rc<Self>, state: State<X, Y>) -> View {
+        view!( <Callouts callouts=(*self).clone() state=state /> )
+    }
+}
+
+/// Moves a label up (or down) until it no longer overlaps any placed labels.
+fn nudge(placed: &[Bounds], mut label: Bounds, up: bool) -> Bounds {
+    // Each step moves past an overlapping label so this ends
+    while let Some(other) = placed.iter().find(|other| other.overlaps(&label)) {
+        let top = if up {
+            other.top_y() - label.height()
+        } else {
+            other.bottom_y()
+        };
+        label = Bounds::from_points(label.left_x(), top, label.right_x(), top + label.height());
+    }
+    label
+}
+
+/// Places labels of the given size (width, height) near their anchor points: above if there's room, otherwise below. Labels are kept apart and inside the inner chart area where possible. Returned in the same order as given.
+fn place_labels(
+    inner: Bounds,
+    anchors: &[(f64, f64)],
+    sizes: &[(f64, f64)],
+    gap: f64,
+) -> Vec<Bounds> {
+    // Place left to right
+    let mut order = (0..anchors.len()).collect::<Vec<_>>();
+    order.sort_by(|&a, &b| anchors[a].0.total_cmp(&anchors[b].0));
+
+    let mut labels = vec![Bounds::default(); anchors.len()];
+    let mut placed = Vec::with_capacity(anchors.len());
+    for index in order {
+        let ((x, y), (width, height)) = (anchors[index], sizes[index]);
+        // Centred over the anchor, within the left and right edges
+        let left = (x - width / 2.0)
+            .min(inner.right_x() - width)
+            .max(inner.left_x());
+        let at = |top: f64| Bounds::from_points(left, top, left + width, top + height);
+
+        let above = nudge(&placed, at(y - gap - height), true);
+        let label = if above.top_y() >= inner.top_y() {
+            above
+        } else {
+            let below = nudge(&placed, at(y + gap), false);
+            if below.bottom_y() <= inner.bottom_y() {
+                below
+            } else {
+                // No room: overlap at the top edge
+                at(inner.top_y())
+            }
+        };
+        placed.push(label);
+        labels[index] = label;
+    }
+    labels
+}
+
+#[component]
+fn Callouts<X: Tick, Y: Tick>(callouts: Callouts<X, Y>, state: State<X, Y>) -> impl IntoView {
+    let debug = state.pre.debug;
+    let font_height = state.pre.font_height;
+    let font_width = state.pre.font_width;
+    let proj = state.projection;
+    let inner = state.layout.inner;
+    let Callouts {
+        points,
+        colour,
+        background,
+    } = callouts;
+
+    // Anchors, label bounds and text
+    let placed = create_memo(move |_| {
+        let (inner, proj) = (inner.get(), proj.get());
+        let (font_height, font_width) = (font_height.get(), font_width.get());
+        let (anchors, texts): (Vec<_>, Vec<_>) = points.with(|points| {
+            points
+                .iter()
+                .map(|(x, y, text)| (proj.position_to_svg(x.position(), y.position()), text))
+                .filter(|&((x, y), _)| inner.contains(x, y))
+                .map(|(anchor, text)| (anchor, text.clone()))
+                .unzip()
+        });
+        let sizes = texts
+            .iter()
+            .map(|text| {
+                let width = text.chars().count() as f64 * font_width + font_width;
+                (width, font_height + font_height / 2.0)
+            })
+            .collect::<Vec<_>>();
+        let labels = place_labels(inner, &anchors, &sizes, font_height);
+        anchors
+            .into_iter()
+            .zip(labels)
+            .zip(texts)
+            .map(|((anchor, label), text)| (anchor, label, text))
+            .collect::<Vec<_>>()
+    });
+
+    let colour = move || colour.get().to_string();
+    let callout = move |((x, y), label, text): ((f64, f64), Bounds, String)| {
+        // Leader line from the anchor to the nearest edge of the label
+        let leader_x = x.clamp(label.left_x(), label.right_x());
+        let leader_y = if label.bottom_y() <= y {
+            label.bottom_y()
+        } else {
+            label.top_y()
+        };
+        view! {
+            <circle cx=x cy=y r=move || font_width.get() / 3.0 fill=colour />
+            <line x1=x y1=y x2=leader_x y2=leader_y stroke=colour />
+            <rect
+                x=label.left_x()
+                y=label.top_y()
+                width=label.width()
+                height=label.height()
+                fill=move || background.get().to_string()
+                stroke=colour />
+            <text
+                x=label.centre_x()
+                y=label.centre_y()
+                fill=colour
+                style="white-space: pre;"
+                font-size=move || font_height.get()
+                text-anchor="middle"
+                dominant-baseline="middle">
+                {text}
+            </text>
+        }
+    };
+
+    view! {
+        <g class="_chartistry_callouts">
+            <DebugRect label="callouts" debug=debug />
+            {move || placed.get().into_iter().map(callout).collect_view()}
+        </g>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inner() -> Bounds {
+        Bounds::new(100.0, 100.0)
+    }
+
+    #[test]
+    fn test_from_data() {
+        let runtime = create_runtime();
+        let data = create_rw_signal(vec![1.0, 5.0]);
+        let callouts = Callouts::from_data(data.into(), |&y: &f64| {
+            (y > 2.0).then(|| (y, y, "big".to_string()))
+        });
+        assert_eq!(callouts.points.get(), vec![(5.0, 5.0, "big".to_string())]);
+        // Follows the data
+        data.set(vec![3.0]);
+        assert_eq!(callouts.points.get(), vec![(3.0, 3.0, "big".to_string())]);
+        runtime.dispose();
+    }
+
+    #[test]
+    fn test_place_above() {
+        let labels = place_labels(inner(), &[(50.0, 50.0)], &[(20.0, 10.0)], 5.0);
+        assert_eq!(labels, vec![Bounds::from_points(40.0, 35.0, 60.0, 45.0)]);
+    }
+
+    #[test]
+    fn test_place_within_edges() {
+        // Near the left edge and no room above
+        let labels = place_labels(inner(), &[(5.0, 10.0)], &[(20.0, 10.0)], 5.0);
+        assert_eq!(labels, vec![Bounds::from_points(0.0, 15.0, 20.0, 25.0)]);
+        // Near the right edge
+        let labels = place_labels(inner(), &[(95.0, 50.0)], &[(20.0, 10.0)], 5.0);
+        assert_eq!(labels, vec![Bounds::from_points(80.0, 35.0, 100.0, 45.0)]);
+    }
+
+    #[test]
+    fn test_place_nudges() {
+        // Same point: second label is nudged up
+        let anchors = [(50.0, 50.0), (50.0, 50.0)];
+        let sizes = [(20.0, 10.0), (20.0, 10.0)];
+        let labels = place_labels(inner(), &anchors, &sizes, 5.0);
+        assert_eq!(
+            labels,
+            vec![
+                Bounds::from_points(40.0, 35.0, 60.0, 45.0),
+                Bounds::from_points(40.0, 25.0, 60.0, 35.0),
+            ]
+        );
+        assert!(!labels[0].overlaps(&labels[1]));
+    }
+
+    #[test]
+    fn test_place_nudges_below() {
+        // Little room above: second label goes below
+        let anchors = [(50.0, 20.0), (50.0, 20.0)];
+        let sizes = [(20.0, 10.0), (20.0, 10.0)];
+        let labels = place_labels(inner(), &anchors, &sizes, 5.0);
+        assert_eq!(
+            labels,
+            vec![
+                Bounds::from_points(40.0, 5.0, 60.0, 15.0),
+                Bounds::from_points(40.0, 25.0, 60.0, 35.0),
+            ]
+        );
+    }
+}
@@ -0,0 +1,54 @@
+use super::UseInner;
+use crate::{context::ChartContext, state::State, Tick};
+use leptos::*;
+use std::rc::Rc;
+
+/// Builds your own inner chart component. Renders SVG within the chart, beneath the series.
+///
+/// The render function is called once with a [ChartContext] giving access to the inner chart bounds, projection (data to SVG coords), data ranges and hover position. Use these in closures to follow changes. For example, a line at the mouse:
+///
+/// ```rust
+/// # use leptos::*;
+/// # use leptos_chartistry::*;
+/// let follow_mouse = CustomInner::new(|ctx: ChartContext<f64, f64>| {
+///     let line = move || {
+///         let (inner, (x, _)) = (ctx.inner_bounds(), ctx.mouse()?);
+///         Some(view! {
+///             <line x1=x y1=inner.top_y() x2=x y2=inner.bottom_y() stroke="red" />
+///         })
+///     };
+///     view!( <g>{line}</g> ).into_view()
+/// });
+/// ```
+pub struct CustomInner<X: 'static, Y: 'static> {
+    render: Rc<dyn Fn(ChartContext<X, Y>) -> View>,
+}
+
+impl<X, Y> Clone for CustomInner<X, Y> {
+    fn clone(&self) -> Self {
+        Self {
+            render: self.render.clone(),
+        }
+    }
+}
+
+impl<X: Tick, Y: Tick> CustomInner<X, Y> {
+    /// Creates a new custom inner component from a render function.
+    pub fn new(render: impl Fn(ChartContext<X, Y>) -> View + 'static) -> Self {
+        Self {
+            render: Rc::new(render),
+        }
+    }
+}
+
+impl<X: Tick, Y: Tick> UseInner<X, Y> for CustomInner<X, Y> {
+    fn render(self: Rc<Self>, state: State<X, Y>) -> View {
+        let ctx = ChartContext::new(&state);
+        view! {
+            <g class="_chartistry_custom_inner">
+                {(self.render)(ctx)}
+            </g>
+        }
+        .into_view()
+    }
+}
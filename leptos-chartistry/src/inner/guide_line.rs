@@ -119,18 +119,20 @@ impl<X, Y> UseInner<X, Y> for UseYGuideLine {
 #[component]
 fn XGuideLine<X: Tick, Y: Tick>(line: XGuideLine, state: State<X, Y>) -> impl IntoView {
     let inner = state.layout.inner;
-    let mouse_chart = state.mouse_chart;
+    let proj = state.projection;
+    let hover_position_x = state.hover_position_x;
 
     // Data alignment
-    let nearest_pos_x = state.pre.data.nearest_position_x(state.hover_position_x);
+    let nearest_pos_x = state.pre.data.nearest_position_x(hover_position_x);
     let nearest_svg_x = create_memo(move |_| {
         nearest_pos_x
             .get()
-            .map(|pos_x| state.projection.get().position_to_svg(pos_x, 0.0).0)
+            .map(|pos_x| proj.get().position_to_svg(pos_x, 0.0).0)
     });
 
     let pos = Signal::derive(move || {
-        let (mouse_x, _) = mouse_chart.get();
+        // Follows the sync group so not necessarily our mouse
+        let (mouse_x, _) = proj.get().position_to_svg(hover_position_x.get(), 0.0);
         let x = match line.align.get() {
             AlignOver::Data => nearest_svg_x.get().unwrap_or(mouse_x),
             AlignOver::Mouse => mouse_x,
@@ -139,8 +141,9 @@ fn XGuideLine<X: Tick, Y: Tick>(line: XGuideLine, state: State<X, Y>) -> impl In
         Bounds::from_points(x, inner.top_y(), x, inner.bottom_y())
     });
 
+    let hover = state.hover_any;
     view! {
-        <GuideLine id="x" width=line.width colour=line.colour state=state pos=pos />
+        <GuideLine id="x" width=line.width colour=line.colour state=state pos=pos hover=hover />
     }
 }
 
@@ -154,8 +157,9 @@ fn YGuideLine<X: 'static, Y: 'static>(line: YGuideLine, state: State<X, Y>) -> i
         let inner = inner.get();
         Bounds::from_points(inner.left_x(), mouse_y, inner.right_x(), mouse_y)
     });
+    let hover = state.hover_inner;
     view! {
-        <GuideLine id="y" width=line.width colour=line.colour state=state pos=pos />
+        <GuideLine id="y" width=line.width colour=line.colour state=state pos=pos hover=hover />
     }
 }
 
@@ -166,9 +170,9 @@ fn GuideLine<X: 'static, Y: 'static>(
     colour: RwSignal<Colour>,
     state: State<X, Y>,
     pos: Signal<Bounds>,
+    hover: Signal<bool>,
 ) -> impl IntoView {
     let debug = state.pre.debug;
-    let hover_inner = state.hover_inner;
 
     let x1 = create_memo(move |_| pos.get().left_x());
     let y1 = create_memo(move |_| pos.get().top_y());
@@ -185,7 +189,7 @@ fn GuideLine<X: 'static, Y: 'static>(
             class=format!("_chartistry_{}_guide_line", id)
             stroke=move || colour.get().to_string()
             stroke-width=width>
-            <Show when=move || hover_inner.get() && have_data.get() >
+            <Show when=move || hover.get() && have_data.get() >
                 <DebugRect label=format!("{}_guide_line", id) debug=debug />
                 <line
                     x1=x1
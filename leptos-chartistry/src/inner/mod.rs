@@ -1,7 +1,13 @@
 pub mod axis_marker;
+pub mod band;
+pub mod brush;
+pub mod callout;
+pub mod custom;
 pub mod grid_line;
 pub mod guide_line;
 pub mod legend;
+pub mod reference_line;
+pub mod zoom;
 
 use crate::{state::State, Tick};
 use leptos::*;
@@ -26,6 +32,22 @@ pub enum InnerLayout<X: Tick, Y: Tick> {
     YGuideLine(guide_line::YGuideLine),
     /// Inset legend. See [InsetLegend](legend::InsetLegend) for details.
     Legend(legend::InsetLegend),
+    /// X brush. See [XBrush](brush::XBrush) for details.
+    XBrush(brush::XBrush<X>),
+    /// Zoom and pan. See [Zoom](zoom::Zoom) for details.
+    Zoom(zoom::Zoom),
+    /// X reference line. See [XReferenceLine](reference_line::XReferenceLine) for details.
+    XReferenceLine(reference_line::XReferenceLine<X>),
+    /// Y reference line. See [YReferenceLine](reference_line::YReferenceLine) for details.
+    YReferenceLine(reference_line::YReferenceLine<Y>),
+    /// X band. See [XBand](band::XBand) for details.
+    XBand(band::XBand<X>),
+    /// Y band. See [YBand](band::YBand) for details.
+    YBand(band::YBand<Y>),
+    /// Callouts. See [Callouts](callout::Callouts) for details.
+    Callouts(callout::Callouts<X, Y>),
+    /// Your own component. See [CustomInner](custom::CustomInner) for details.
+    Custom(custom::CustomInner<X, Y>),
 }
 
 /// Convert a type (e.g., a [guide line](struct@guide_line::XGuideLine)) into an inner layout for use in a [Chart](crate::Chart).
@@ -43,6 +65,30 @@ impl<X: Tick, Y: Tick> InnerLayout<X, Y> {
             Self::XGuideLine(inner) => inner.use_horizontal(),
             Self::YGuideLine(inner) => inner.use_vertical(),
             Self::Legend(inner) => Rc::new(inner),
+            Self::Zoom(inner) => Rc::new(inner),
+            Self::XBrush(inner) => Rc::new(inner),
+            Self::XReferenceLine(inner) => Rc::new(inner),
+            Self::YReferenceLine(inner) => Rc::new(inner),
+            Self::XBand(inner) => Rc::new(inner),
+            Self::YBand(inner) => Rc::new(inner),
+            Self::Callouts(inner) => Rc::new(inner),
+            Self::Custom(inner) => Rc::new(inner),
+        }
+    }
+
+    /// X values that the X axis range must include.
+    pub(crate) fn extend_x(&self) -> Vec<X> {
+        match self {
+            Self::XReferenceLine(inner) => vec![inner.value.get()],
+            _ => vec![],
+        }
+    }
+
+    /// Y values that the Y axis range must include.
+    pub(crate) fn extend_y(&self) -> Vec<Y> {
+        match self {
+            Self::YReferenceLine(inner) => vec![inner.value.get()],
+            _ => vec![],
         }
     }
 }
@@ -78,3 +124,11 @@ impl_into_inner!(grid_line::YGridLine<Y>, YGridLine);
 impl_into_inner!(guide_line::XGuideLine, XGuideLine);
 impl_into_inner!(guide_line::YGuideLine, YGuideLine);
 impl_into_inner!(legend::InsetLegend, Legend);
+impl_into_inner!(zoom::Zoom, Zoom);
+impl_into_inner!(brush::XBrush<X>, XBrush);
+impl_into_inner!(reference_line::XReferenceLine<X>, XReferenceLine);
+impl_into_inner!(reference_line::YReferenceLine<Y>, YReferenceLine);
+impl_into_inner!(band::XBand<X>, XBand);
+impl_into_inner!(band::YBand<Y>, YBand);
+impl_into_inner!(callout::Callouts<X, Y>, Callouts);
+impl_into_inner!(custom::CustomInner<X, Y>, Custom);
@@ -0,0 +1,252 @@
+use super::UseInner;
+use crate::{colours::Colour, debug::DebugRect, state::State, Tick};
+use leptos::*;
+use std::{rc::Rc, str::FromStr};
+
+/// Default colour for reference lines.
+pub const REFERENCE_LINE_COLOUR: Colour = Colour::from_rgb(0x6E, 0x6E, 0x6E);
+
+macro_rules! impl_reference_line {
+    ($name:ident, $t:ident, $axis:literal) => {
+        #[doc = concat!("Builds a reference line at a fixed ", $axis, " value e.g., a deploy time or a threshold. Extends the ", $axis, " axis range to include the value.")]
+        #[derive(Clone, Debug, PartialEq)]
+        pub struct $name<$t: 'static> {
+            #[doc = concat!($axis, " value of the line.")]
+            pub value: RwSignal<$t>,
+            /// Label shown next to the line. Empty for no label.
+            pub label: RwSignal<String>,
+            /// Colour of the line and label.
+            pub colour: RwSignal<Colour>,
+            /// Width of the line.
+            pub width: RwSignal<f64>,
+            /// Dash style of the line. Default is [Dash::Dashed].
+            pub dash: RwSignal<Dash>,
+        }
+
+        impl<$t: Tick> $name<$t> {
+            #[doc = concat!("Creates a new reference line at the given ", $axis, " value.")]
+            pub fn new(value: impl Into<$t>) -> Self {
+                Self {
+                    value: create_rw_signal(value.into()),
+                    label: RwSignal::default(),
+                    colour: create_rw_signal(REFERENCE_LINE_COLOUR),
+                    width: create_rw_signal(1.0),
+                    dash: RwSignal::default(),
+                }
+            }
+
+            /// Sets the label shown next to the line.
+            pub fn with_label(self, label: impl Into<String>) -> Self {
+                self.label.set(label.into());
+                self
+            }
+
+            /// Sets the colour of the line and label.
+            pub fn with_colour(self, colour: impl Into<Colour>) -> Self {
+                self.colour.set(colour.into());
+                self
+            }
+
+            /// Sets the width of the line.
+            pub fn with_width(self, width: impl Into<f64>) -> Self {
+                self.width.set(width.into());
+                self
+            }
+
+            /// Sets the dash style of the line.
+            pub fn with_dash(self, dash: impl Into<Dash>) -> Self {
+                self.dash.set(dash.into());
+                self
+            }
+        }
+    };
+}
+
+impl_reference_line!(XReferenceLine, X, "X");
+impl_reference_line!(YReferenceLine, Y, "Y");
+
+/// Dash style of a line.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub enum Dash {
+    /// A continuous line.
+    Solid,
+    /// Long dashes.
+    #[default]
+    Dashed,
+    /// Short dots.
+    Dotted,
+}
+
+impl Dash {
+    /// Returns the SVG `stroke-dasharray` for a line of the given width.
+    pub(crate) fn to_svg_attr(self, width: f64) -> Option<String> {
+        match self {
+            Self::Solid => None,
+            Self::Dashed => Some(format!("{} {}", width * 6.0, width * 4.0)),
+            Self::Dotted => Some(format!("{} {}", width, width * 3.0)),
+        }
+    }
+}
+
+impl std::fmt::Display for Dash {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Solid => write!(f, "solid"),
+            Self::Dashed => write!(f, "dashed"),
+            Self::Dotted => write!(f, "dotted"),
+        }
+    }
+}
+
+impl FromStr for Dash {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "solid" => Ok(Self::Solid),
+            "dashed" => Ok(Self::Dashed),
+            "dotted" => Ok(Self::Dotted),
+            _ => Err(format!("unknown dash: `{}`", s)),
+        }
+    }
+}
+
+impl<X: Tick, Y: Tick> UseInner<X, Y> for XReferenceLine<X> {
+    fn render(self: Rc<Self>, state: State<X, Y>) -> View {
+        let line = (*self).clone();
+        let proj = state.projection;
+        let inner = state.layout.inner;
+        let font_width = state.pre.font_width;
+        // Vertical line with the label at the top, to the right of the line
+        let coords = Signal::derive(move || {
+            let (x, _) = proj.get().position_to_svg(line.value.get().position(), 0.0);
+            let inner = inner.get();
+            (x, inner.top_y(), x, inner.bottom_y())
+        });
+        let label_at = Signal::derive(move || {
+            let (x, top, _, _) = coords.get();
+            (x + font_width.get() / 2.0, top, "start", "hanging")
+        });
+        view! {
+            <ReferenceLine
+                id="x"
+                label=line.label
+                colour=line.colour
+                width=line.width
+                dash=line.dash
+                coords=coords
+                label_at=label_at
+                state=state />
+        }
+    }
+}
+
+impl<X: Tick, Y: Tick> UseInner<X, Y> for YReferenceLine<Y> {
+    fn render(self: Rc<Self>, state: State<X, Y>) -> View {
+        let line = (*self).clone();
+        let proj = state.projection;
+        let inner = state.layout.inner;
+        let font_width = state.pre.font_width;
+        // Horizontal line with the label at the right, above the line
+        let coords = Signal::derive(move || {
+            let (_, y) = proj.get().position_to_svg(0.0, line.value.get().position());
+            let inner = inner.get();
+            (inner.left_x(), y, inner.right_x(), y)
+        });
+        let label_at = Signal::derive(move || {
+            let (_, y, right, _) = coords.get();
+            (right - font_width.get() / 2.0, y, "end", "text-after-edge")
+        });
+        view! {
+            <ReferenceLine
+                id="y"
+                label=line.label
+                colour=line.colour
+                width=line.width
+                dash=line.dash
+                coords=coords
+                label_at=label_at
+                state=state />
+        }
+    }
+}
+
+#[component]
+fn ReferenceLine<X: 'static, Y: 'static>(
+    id: &'static str,
+    label: RwSignal<String>,
+    colour: RwSignal<Colour>,
+    width: RwSignal<f64>,
+    dash: RwSignal<Dash>,
+    coords: Signal<(f64, f64, f64, f64)>,
+    // Label position: X, Y, text anchor, dominant baseline
+    label_at: Signal<(f64, f64, &'static str, &'static str)>,
+    state: State<X, Y>,
+) -> impl IntoView {
+    let debug = state.pre.debug;
+    let font_height = state.pre.font_height;
+    let inner = state.layout.inner;
+
+    // Hide if outside the inner chart area (e.g., zoomed) or no data
+    let in_bounds = create_memo(move |_| {
+        // Allow for rounding when placed on the edge
+        const EPSILON: f64 = 0.5;
+        let (x1, y1, x2, y2) = coords.get();
+        let inner = inner.get();
+        let (left, right) = (inner.left_x() - EPSILON, inner.right_x() + EPSILON);
+        let (top, bottom) = (inner.top_y() - EPSILON, inner.bottom_y() + EPSILON);
+        [x1, x2].iter().all(|&x| left <= x && x <= right)
+            && [y1, y2].iter().all(|&y| top <= y && y <= bottom)
+    });
+    let colour = move || colour.get().to_string();
+
+    view! {
+        <g
+            class=format!("_chartistry_{}_reference_line", id)
+            stroke=colour
+            stroke-width=width
+            stroke-dasharray=move || dash.get().to_svg_attr(width.get())>
+            <Show when=move || in_bounds.get()>
+                <DebugRect label=format!("{}_reference_line", id) debug=debug />
+                <line
+                    x1=move || coords.get().0
+                    y1=move || coords.get().1
+                    x2=move || coords.get().2
+                    y2=move || coords.get().3
+                />
+                <text
+                    x=move || label_at.get().0
+                    y=move || label_at.get().1
+                    stroke="none"
+                    fill=colour
+                    style="white-space: pre;"
+                    font-size=move || font_height.get()
+                    text-anchor=move || label_at.get().2
+                    dominant-baseline=move || label_at.get().3>
+                    {label}
+                </text>
+            </Show>
+        </g>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dash_svg_attr() {
+        assert_eq!(Dash::Solid.to_svg_attr(2.0), None);
+        assert_eq!(Dash::Dashed.to_svg_attr(2.0), Some("12 8".to_string()));
+        assert_eq!(Dash::Dotted.to_svg_attr(2.0), Some("2 6".to_string()));
+    }
+
+    #[test]
+    fn test_dash_from_str() {
+        for dash in [Dash::Solid, Dash::Dashed, Dash::Dotted] {
+            assert_eq!(dash.to_string().parse::<Dash>(), Ok(dash));
+        }
+        assert!("wavy".parse::<Dash>().is_err());
+    }
+}
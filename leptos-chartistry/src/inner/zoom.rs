@@ -0,0 +1,345 @@
+use super::UseInner;
+use crate::{
+    bounds::Bounds, colours::Colour, debug::DebugRect, projection::Projection, series::Range,
+    state::State, Tick,
+};
+use leptos::*;
+use leptos_use::{use_event_listener, use_window};
+use std::{rc::Rc, str::FromStr};
+
+/// Default colour for the zoom selection.
+pub const ZOOM_COLOUR: Colour = Colour::from_rgb(0x9A, 0x9A, 0x9A);
+
+/// Minimum size (in pixels) of a selection before it zooms. Avoids zooming on a click.
+pub(super) const MIN_SELECTION: f64 = 4.0;
+/// How much a single mouse wheel step zooms by.
+const WHEEL_ZOOM: f64 = 1.2;
+
+/// Builds interactive zoom and pan on the inner chart area.
+///
+/// Dragging the mouse selects an area to zoom in to (a rubber band) or pans the chart. Holding shift swaps the two. The mouse wheel zooms in and out around the mouse. A double click resets the zoom.
+///
+/// Zooming writes to the series' [zoom_x](crate::Series::zoom_x) and [zoom_y](crate::Series::zoom_y) signals. Read them to follow the visible range or set them to `None` to reset. Requires `X` and `Y` to support [Tick::at_position].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Zoom {
+    /// What dragging the mouse does. Holding shift swaps selecting and panning. Default is [ZoomDrag::Select].
+    pub drag: RwSignal<ZoomDrag>,
+    /// Whether the mouse wheel zooms. Default is true.
+    pub wheel: RwSignal<bool>,
+    /// Which axes are zoomed and panned. Default is [ZoomAxes::Both].
+    pub axes: RwSignal<ZoomAxes>,
+    /// Colour of the selection.
+    pub colour: RwSignal<Colour>,
+}
+
+/// What dragging the mouse does on a [Zoom].
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub enum ZoomDrag {
+    /// Select an area to zoom in to.
+    #[default]
+    Select,
+    /// Pan the chart.
+    Pan,
+}
+
+/// Axes that a [Zoom] changes.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub enum ZoomAxes {
+    /// Only the X axis.
+    X,
+    /// Only the Y axis.
+    Y,
+    /// Both X and Y axes.
+    #[default]
+    Both,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Drag {
+    Select { start: (f64, f64) },
+    Pan { start: (f64, f64), proj: Projection },
+}
+
+impl Zoom {
+    /// Sets what dragging the mouse does.
+    pub fn with_drag(self, drag: impl Into<ZoomDrag>) -> Self {
+        self.drag.set(drag.into());
+        self
+    }
+
+    /// Sets whether the mouse wheel zooms.
+    pub fn with_wheel(self, wheel: impl Into<bool>) -> Self {
+        self.wheel.set(wheel.into());
+        self
+    }
+
+    /// Sets which axes are zoomed and panned.
+    pub fn with_axes(self, axes: impl Into<ZoomAxes>) -> Self {
+        self.axes.set(axes.into());
+        self
+    }
+
+    /// Sets the colour of the selection.
+    pub fn with_colour(self, colour: impl Into<Colour>) -> Self {
+        self.colour.set(colour.into());
+        self
+    }
+}
+
+impl Default for Zoom {
+    fn default() -> Self {
+        Self {
+            drag: RwSignal::default(),
+            wheel: create_rw_signal(true),
+            axes: RwSignal::default(),
+            colour: create_rw_signal(ZOOM_COLOUR),
+        }
+    }
+}
+
+impl ZoomAxes {
+    fn x(self) -> bool {
+        matches!(self, Self::X | Self::Both)
+    }
+
+    fn y(self) -> bool {
+        matches!(self, Self::Y | Self::Both)
+    }
+}
+
+impl std::fmt::Display for ZoomDrag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Select => write!(f, "select"),
+            Self::Pan => write!(f, "pan"),
+        }
+    }
+}
+
+impl FromStr for ZoomDrag {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "select" => Ok(Self::Select),
+            "pan" => Ok(Self::Pan),
+            _ => Err(format!("unknown zoom drag: `{}`", s)),
+        }
+    }
+}
+
+impl std::fmt::Display for ZoomAxes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::X => write!(f, "x"),
+            Self::Y => write!(f, "y"),
+            Self::Both => write!(f, "both"),
+        }
+    }
+}
+
+impl FromStr for ZoomAxes {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "x" => Ok(Self::X),
+            "y" => Ok(Self::Y),
+            "both" => Ok(Self::Both),
+            _ => Err(format!("unknown zoom axes: `{}`", s)),
+        }
+    }
+}
+
+impl<X: Tick, Y: Tick> UseInner<X, Y> for Zoom {
+    fn render(self: Rc<Self>, state: State<X, Y>) -> View {
+        view!( <Zoom zoom=(*self).clone() state=state /> )
+    }
+}
+
+/// Maps two positions to ticks using the current range for context.
+pub(super) fn ticks_at_positions<T: Tick>(
+    range: Memo<Range<T>>,
+    first: f64,
+    last: f64,
+) -> Option<(T, T)> {
+    range.with_untracked(|range| {
+        let (reference, _) = range.range()?;
+        Some((reference.at_position(first)?, reference.at_position(last)?))
+    })
+}
+
+#[component]
+fn Zoom<X: Tick, Y: Tick>(zoom: Zoom, state: State<X, Y>) -> impl IntoView {
+    let debug = state.pre.debug;
+    let data = &state.pre.data;
+    let (zoom_x, zoom_y) = (data.zoom_x, data.zoom_y);
+    let (range_x, range_y) = (data.range_x, data.range_y);
+    let proj = state.projection;
+    let mouse = state.mouse_chart;
+    let hover_inner = state.hover_inner;
+    let inner = state.layout.inner;
+    let root = state.root;
+    let Zoom {
+        drag,
+        wheel,
+        axes,
+        colour,
+    } = zoom;
+
+    // Zooms to the SVG rectangle between two points using the given projection
+    let set_zoom = move |proj: &Projection, (x1, y1): (f64, f64), (x2, y2): (f64, f64)| {
+        let (left, bottom) = proj.svg_to_position(x1.min(x2), y1.max(y2));
+        let (right, top) = proj.svg_to_position(x1.max(x2), y1.min(y2));
+        let axes = axes.get_untracked();
+        if axes.x() {
+            if let Some(range) = ticks_at_positions(range_x, left, right) {
+                zoom_x.set(Some(range));
+            }
+        }
+        if axes.y() {
+            if let Some(range) = ticks_at_positions(range_y, bottom, top) {
+                zoom_y.set(Some(range));
+            }
+        }
+    };
+
+    // Start dragging
+    let dragging = create_rw_signal::<Option<Drag>>(None);
+    _ = use_event_listener(root, ev::mousedown, move |ev| {
+        if ev.button() != 0 || !hover_inner.get_untracked() {
+            return;
+        }
+        // Avoid selecting text
+        ev.prevent_default();
+        let start = mouse.get_untracked();
+        let pan = (drag.get_untracked() == ZoomDrag::Pan) != ev.shift_key();
+        dragging.set(Some(if pan {
+            Drag::Pan {
+                start,
+                proj: proj.get_untracked(),
+            }
+        } else {
+            Drag::Select { start }
+        }));
+    });
+
+    // Selection: clamped to the inner chart area and spans axes that aren't zoomed
+    let selection = create_memo(move |_| {
+        let Some(Drag::Select { start }) = dragging.get() else {
+            return None;
+        };
+        let (end, inner, axes) = (mouse.get(), inner.get(), axes.get());
+        let (x1, x2) = if axes.x() {
+            let clamp = |x: f64| x.clamp(inner.left_x(), inner.right_x());
+            (clamp(start.0), clamp(end.0))
+        } else {
+            (inner.left_x(), inner.right_x())
+        };
+        let (y1, y2) = if axes.y() {
+            let clamp = |y: f64| y.clamp(inner.top_y(), inner.bottom_y());
+            (clamp(start.1), clamp(end.1))
+        } else {
+            (inner.top_y(), inner.bottom_y())
+        };
+        Some(Bounds::from_points(
+            x1.min(x2),
+            y1.min(y2),
+            x1.max(x2),
+            y1.max(y2),
+        ))
+    });
+
+    // Pan: offset the projection we started with by the mouse movement
+    let panned = store_value(false);
+    create_effect(move |_| {
+        let Some(Drag::Pan { start, proj }) = dragging.get() else {
+            panned.set_value(false);
+            return;
+        };
+        let (x, y) = mouse.get();
+        let (dx, dy) = (x - start.0, y - start.1);
+        // Avoid zooming (and fixing the range) on a click
+        if !panned.get_value() && dx.abs() < MIN_SELECTION && dy.abs() < MIN_SELECTION {
+            return;
+        }
+        panned.set_value(true);
+        let bounds = proj.bounds();
+        set_zoom(
+            &proj,
+            (bounds.left_x() - dx, bounds.top_y() - dy),
+            (bounds.right_x() - dx, bounds.bottom_y() - dy),
+        );
+    });
+
+    // Stop dragging. Listen on the window in case the mouse leaves the chart
+    _ = use_event_listener(use_window(), ev::mouseup, move |_| {
+        if let Some(sel) = selection.get_untracked() {
+            if sel.width() >= MIN_SELECTION && sel.height() >= MIN_SELECTION {
+                set_zoom(
+                    &proj.get_untracked(),
+                    (sel.left_x(), sel.top_y()),
+                    (sel.right_x(), sel.bottom_y()),
+                );
+            }
+        }
+        dragging.set(None);
+    });
+
+    // Wheel zooms around the mouse
+    _ = use_event_listener(root, ev::wheel, move |ev| {
+        if !wheel.get_untracked() || !hover_inner.get_untracked() {
+            return;
+        }
+        ev.prevent_default();
+        let proj = proj.get_untracked();
+        let bounds = proj.bounds();
+        let (mouse_x, mouse_y) = mouse.get_untracked();
+        // Scroll up to zoom in
+        let scale = if ev.delta_y() < 0.0 {
+            1.0 / WHEEL_ZOOM
+        } else {
+            WHEEL_ZOOM
+        };
+        let scale_x = |x: f64| mouse_x + (x - mouse_x) * scale;
+        let scale_y = |y: f64| mouse_y + (y - mouse_y) * scale;
+        set_zoom(
+            &proj,
+            (scale_x(bounds.left_x()), scale_y(bounds.top_y())),
+            (scale_x(bounds.right_x()), scale_y(bounds.bottom_y())),
+        );
+    });
+
+    // Double click resets
+    _ = use_event_listener(root, ev::dblclick, move |_| {
+        if !hover_inner.get_untracked() {
+            return;
+        }
+        let axes = axes.get_untracked();
+        if axes.x() {
+            zoom_x.set(None);
+        }
+        if axes.y() {
+            zoom_y.set(None);
+        }
+    });
+
+    let colour = move || colour.get().to_string();
+    view! {
+        <g class="_chartistry_zoom">
+            <DebugRect label="zoom" debug=debug />
+            {move || selection.get().map(|sel| view! {
+                <rect
+                    x=sel.left_x()
+                    y=sel.top_y()
+                    width=sel.width()
+                    height=sel.height()
+                    fill=colour
+                    fill-opacity=0.2
+                    stroke=colour />
+            })}
+        </g>
+    }
+}
@@ -17,6 +17,7 @@ pub struct Layout {
     pub left: Vec<Memo<Bounds>>,
     pub inner: Memo<Bounds>,
     pub x_width: Memo<f64>,
+    pub y_width: Memo<f64>,
 }
 
 #[derive(Clone)]
@@ -107,9 +108,11 @@ impl Layout {
             Bounds::from_points(outer.get().left_x(), i.top_y(), i.left_x(), i.bottom_y())
         });
 
-        // Find the width of each X
-        let data_len = state.data.len;
-        let x_width = create_memo(move |_| inner.get().width() / data_len.get() as f64);
+        // Find the width of each X. Wider when zoomed in
+        let (slots_x, slots_y) = (state.data.slots_x, state.data.slots_y);
+        let x_width = create_memo(move |_| inner.get().width() / slots_x.get());
+        // Find the height of each Y (used by horizontal bars)
+        let y_width = create_memo(move |_| inner.get().height() / slots_y.get());
 
         // State signals
         let layout = Layout {
@@ -120,6 +123,7 @@ impl Layout {
             left: option_bounds(Edge::Left, left_bounds, left_widths),
             inner,
             x_width,
+            y_width,
         };
 
         let vertical = |edge, bounds: &[Memo<Bounds>], items: Vec<_>| {
@@ -0,0 +1,88 @@
+use super::{UseLayout, UseVerticalLayout};
+use crate::{bounds::Bounds, debug::DebugRect, edge::Edge, state::State, EdgeContext};
+use leptos::*;
+use std::rc::Rc;
+
+/// Builds your own edge component e.g., a summary strip or unit selector. Takes part in the chart's layout like other edge components.
+///
+/// Has a fixed size: the height when placed on the top or bottom edge or the width on the left or right edge. The render function is called once with an [EdgeContext] giving the bounds it's been given. Render SVG within those bounds or use a `<foreignObject>` for HTML:
+///
+/// ```rust
+/// # use leptos::*;
+/// # use leptos_chartistry::*;
+/// let strip = CustomEdge::new(24.0, |ctx: EdgeContext| {
+///     let bounds = move || ctx.bounds();
+///     view! {
+///         <foreignObject
+///             x=move || bounds().left_x()
+///             y=move || bounds().top_y()
+///             width=move || bounds().width()
+///             height=move || bounds().height()>
+///             <p style="margin: 0;">"Total: 42"</p>
+///         </foreignObject>
+///     }
+///     .into_view()
+/// });
+/// ```
+pub struct CustomEdge {
+    /// Fixed size of the component: height on a top or bottom edge or width on a left or right edge.
+    pub size: RwSignal<f64>,
+    render: Rc<dyn Fn(EdgeContext) -> View>,
+}
+
+impl Clone for CustomEdge {
+    fn clone(&self) -> Self {
+        Self {
+            size: self.size,
+            render: self.render.clone(),
+        }
+    }
+}
+
+impl CustomEdge {
+    /// Creates a new custom edge component of a fixed size from a render function.
+    pub fn new(size: impl Into<f64>, render: impl Fn(EdgeContext) -> View + 'static) -> Self {
+        Self {
+            size: create_rw_signal(size.into()),
+            render: Rc::new(render),
+        }
+    }
+
+    /// Sets the fixed size of the component.
+    pub fn with_size(self, size: impl Into<f64>) -> Self {
+        self.size.set(size.into());
+        self
+    }
+
+    pub(super) fn fixed_height(&self) -> Signal<f64> {
+        self.size.into()
+    }
+
+    pub(super) fn to_horizontal_use(&self) -> UseLayout {
+        UseLayout::Custom(self.clone())
+    }
+
+    pub(super) fn to_vertical_use(&self) -> UseVerticalLayout {
+        UseVerticalLayout {
+            width: self.size.into(),
+            layout: UseLayout::Custom(self.clone()),
+        }
+    }
+}
+
+#[component]
+pub(super) fn CustomEdge<X: 'static, Y: 'static>(
+    custom: CustomEdge,
+    edge: Edge,
+    bounds: Memo<Bounds>,
+    state: State<X, Y>,
+) -> impl IntoView {
+    let debug = state.pre.debug;
+    let ctx = EdgeContext::new(edge, bounds, &state);
+    view! {
+        <g class="_chartistry_custom_edge">
+            <DebugRect label="CustomEdge" debug=debug bounds=vec![bounds.into()] />
+            {(custom.render)(ctx)}
+        </g>
+    }
+}
@@ -10,6 +10,8 @@ use crate::{
 use leptos::*;
 
 /// Builds a legend for the chart [series](crate::Series). Orientated along the axis of its placed edge. Drawn in HTML.
+///
+/// Clicking an entry hides or shows that series. See [Series::hidden](crate::Series::hidden).
 #[derive(Clone, Debug)]
 pub struct Legend {
     /// Anchor of the legend.
@@ -103,8 +105,10 @@ pub(crate) fn Legend<X: Clone + 'static, Y: Clone + 'static>(
         } else {
             view!(<VerticalBody series=series state=state.clone() />)
         };
+        // Namespace needed by standalone SVG (e.g., rendered to a string)
         view! {
             <div
+                xmlns="http://www.w3.org/1999/xhtml"
                 style="display: flex; height: 100%; overflow: auto;"
                 style:flex-direction={if edge.is_horizontal() { "row" } else { "column" }}
                 style:justify-content=move || anchor.get().css_justify_content()>
@@ -150,7 +154,7 @@ fn VerticalBody<X: Clone + 'static, Y: Clone + 'static>(
             let:series>
             <tr>
                 <td style:padding=padding>
-                    <Snippet series=series state=state.clone() />
+                    <Entry series=series state=state.clone() />
                 </td>
             </tr>
         </For>
@@ -174,9 +178,37 @@ fn HorizontalBody<X: Clone + 'static, Y: Clone + 'static>(
                 key=|(_, series)| series.id
                 let:series>
                 <td style:padding-left=move || padding_left(series.0)>
-                    <Snippet series=series.1 state=state.clone() />
+                    <Entry series=series.1 state=state.clone() />
                 </td>
             </For>
         </tr>
     }
 }
+
+#[component]
+fn Entry<X: Clone + 'static, Y: Clone + 'static>(
+    series: UseY,
+    state: State<X, Y>,
+) -> impl IntoView {
+    let hidden = state.pre.data.hidden;
+    let (id, hover_series) = (series.id, state.hover_series);
+    let is_hidden = create_memo(move |_| hidden.with(|hidden| hidden.contains(&id)));
+    // Toggle visibility
+    let on_click = move |_| {
+        hidden.update(|hidden| {
+            if !hidden.remove(&id) {
+                hidden.insert(id);
+            }
+        });
+    };
+    view! {
+        <div
+            class="_chartistry_legend_entry"
+            style="cursor: pointer; user-select: none;"
+            style:opacity=move || if is_hidden.get() { "0.4" } else { "1" }
+            style:font-weight=move || (hover_series.get() == Some(id)).then_some("bold")
+            on:click=on_click>
+            <Snippet series=series state=state />
+        </div>
+    }
+}
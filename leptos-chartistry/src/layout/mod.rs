@@ -1,4 +1,5 @@
 mod compose;
+pub mod custom;
 pub mod legend;
 pub mod rotated_label;
 pub mod tick_labels;
@@ -26,6 +27,8 @@ pub enum EdgeLayout<Tick: 'static> {
     RotatedLabel(rotated_label::RotatedLabel),
     /// Tick labels. See [tick_labels](struct@tick_labels::TickLabels) for details.
     TickLabels(tick_labels::TickLabels<Tick>),
+    /// Your own component. See [CustomEdge](struct@custom::CustomEdge) for details.
+    Custom(custom::CustomEdge),
 }
 
 struct UseVerticalLayout {
@@ -38,6 +41,7 @@ enum UseLayout {
     Legend(legend::Legend),
     RotatedLabel(rotated_label::RotatedLabel),
     TickLabels(tick_labels::UseTickLabels),
+    Custom(custom::CustomEdge),
 }
 
 impl UseLayout {
@@ -57,6 +61,9 @@ impl UseLayout {
             Self::TickLabels(inner) => view! {
                 <tick_labels::TickLabels ticks=inner edge=edge bounds=bounds state=state />
             },
+            Self::Custom(inner) => view! {
+                <custom::CustomEdge custom=inner edge=edge bounds=bounds state=state />
+            },
         }
     }
 }
@@ -67,6 +74,7 @@ impl<Tick: crate::Tick> EdgeLayout<Tick> {
             Self::Legend(inner) => inner.fixed_height(state),
             Self::RotatedLabel(inner) => inner.fixed_height(state),
             Self::TickLabels(inner) => inner.fixed_height(state),
+            Self::Custom(inner) => inner.fixed_height(),
         }
     }
 }
@@ -77,6 +85,7 @@ impl<X: Tick> EdgeLayout<X> {
             Self::Legend(inner) => inner.to_horizontal_use(),
             Self::RotatedLabel(inner) => inner.to_horizontal_use(),
             Self::TickLabels(inner) => inner.to_horizontal_use(state, avail_width),
+            Self::Custom(inner) => inner.to_horizontal_use(),
         }
     }
 }
@@ -91,6 +100,7 @@ impl<Y: Tick> EdgeLayout<Y> {
             Self::Legend(inner) => inner.to_vertical_use(state),
             Self::RotatedLabel(inner) => inner.to_vertical_use(state),
             Self::TickLabels(inner) => inner.to_vertical_use(state, avail_height),
+            Self::Custom(inner) => inner.to_vertical_use(),
         }
     }
 }
@@ -125,3 +135,4 @@ macro_rules! impl_into_edge {
 impl_into_edge!(legend::Legend, Legend);
 impl_into_edge!(rotated_label::RotatedLabel, RotatedLabel);
 impl_into_edge!(tick_labels::TickLabels<V>, TickLabels);
+impl_into_edge!(custom::CustomEdge, Custom);
@@ -5,10 +5,10 @@ use crate::{
     edge::Edge,
     state::{PreState, State},
     ticks::{
-        AlignedFloats, GeneratedTicks, HorizontalSpan, TickFormat, TickFormatFn, TickGen,
-        Timestamps, VerticalSpan,
+        AlignedFloats, AlignedIntegers, Category, CategoryTicks, GeneratedTicks, HorizontalSpan,
+        LogFloats, TickFormat, TickFormatFn, TickGen, Timestamps, VerticalSpan,
     },
-    Tick,
+    Tick, YAxis,
 };
 use chrono::prelude::*;
 use leptos::*;
@@ -26,11 +26,14 @@ pub struct TickLabels<Tick: 'static> {
     pub format: RwSignal<Rc<TickFormatFn<Tick>>>,
     /// Tick generator for the labels.
     pub generator: RwSignal<Rc<dyn TickGen<Tick = Tick>>>,
+    /// Which Y axis the labels describe. Only used on the left and right edges. Default is [YAxis::Primary].
+    pub y_axis: RwSignal<YAxis>,
 }
 
 #[derive(Clone)]
 pub struct UseTickLabels {
     ticks: Signal<Vec<(f64, String)>>,
+    y_axis: Signal<YAxis>,
 }
 
 impl<Tick> Clone for TickLabels<Tick> {
@@ -39,6 +42,7 @@ impl<Tick> Clone for TickLabels<Tick> {
             min_chars: self.min_chars,
             format: self.format,
             generator: self.generator,
+            y_axis: self.y_axis,
         }
     }
 }
@@ -54,6 +58,28 @@ impl TickLabels<f64> {
     pub fn aligned_floats() -> Self {
         Self::from_generator(AlignedFloats::default())
     }
+
+    /// Creates a new tick label generator for floating point numbers on a log scale. See [LogFloats] for details.
+    pub fn log_floats() -> Self {
+        Self::from_generator(LogFloats::default())
+    }
+}
+
+impl<I: crate::Tick> TickLabels<I>
+where
+    AlignedIntegers<I>: TickGen<Tick = I> + Default + 'static,
+{
+    /// Creates a new tick label generator for integers. See [AlignedIntegers] for details.
+    pub fn aligned_integers() -> Self {
+        Self::from_generator(AlignedIntegers::default())
+    }
+}
+
+impl TickLabels<Category> {
+    /// Creates a new tick label generator for categories. See [CategoryTicks] for details.
+    pub fn categories() -> Self {
+        Self::from_generator(CategoryTicks::default())
+    }
 }
 
 impl<Tz> TickLabels<DateTime<Tz>>
@@ -74,6 +100,7 @@ impl<Tick: crate::Tick> TickLabels<Tick> {
             min_chars: RwSignal::default(),
             format: RwSignal::new(HorizontalSpan::identity_format()),
             generator: create_rw_signal(Rc::new(gen)),
+            y_axis: RwSignal::default(),
         }
     }
 
@@ -83,6 +110,12 @@ impl<Tick: crate::Tick> TickLabels<Tick> {
         self
     }
 
+    /// Sets which Y axis the labels describe. Use [YAxis::Secondary] to label a secondary axis, typically on the right edge.
+    pub fn with_y_axis(self, y_axis: impl Into<YAxis>) -> Self {
+        self.y_axis.set(y_axis.into());
+        self
+    }
+
     /// Sets the format function for the tick labels.
     ///
     /// This is a function that takes a `Tick` and a formatter and returns a `String`. It gives an opportunity to customise tick label format. The formatter is the resulting state of the tick generator and does the default aciton. For example if aligned floats decides to use "1000s" then the formatter will use that.
@@ -126,16 +159,17 @@ impl<X: Tick> TickLabels<X> {
     ) -> Signal<GeneratedTicks<X>> {
         let font_width = state.font_width;
         let padding = state.padding;
-        let range_x = state.data.range_x;
+        let (range_x, scale_x) = (state.data.range_x, state.data.scale_x);
         let TickLabels {
             min_chars,
             format,
             generator,
+            ..
         } = self.clone();
         create_memo(move |_| {
             range_x
                 .get()
-                .range()
+                .range_for(scale_x.get())
                 .map(|(first, last)| {
                     let span = HorizontalSpan::new(
                         font_width.get(),
@@ -164,6 +198,7 @@ impl<X: Tick> TickLabels<X> {
     ) -> UseLayout {
         UseLayout::TickLabels(UseTickLabels {
             ticks: self.map_ticks(self.generate_x(state, avail_width.into())),
+            y_axis: self.y_axis.into(),
         })
     }
 }
@@ -176,12 +211,13 @@ impl<Y: Tick> TickLabels<Y> {
     ) -> Signal<GeneratedTicks<Y>> {
         let font_height = state.font_height;
         let padding = state.padding;
-        let range_y = state.data.range_y;
+        let range_y = state.data.range_y_for(self.y_axis.into());
+        let scale_y = state.data.scale_y;
         let generator = self.generator;
         create_memo(move |_| {
             range_y
                 .get()
-                .range()
+                .range_for(scale_y.get())
                 .map(|(first, last)| {
                     let span = VerticalSpan::new(
                         font_height.get() + padding.get().height(),
@@ -202,7 +238,10 @@ impl<Y: Tick> TickLabels<Y> {
         let ticks = self.map_ticks(self.generate_y(state, avail_height.into()));
         UseVerticalLayout {
             width: mk_width(self.min_chars, state, ticks),
-            layout: UseLayout::TickLabels(UseTickLabels { ticks }),
+            layout: UseLayout::TickLabels(UseTickLabels {
+                ticks,
+                y_axis: self.y_axis.into(),
+            }),
         }
     }
 }
@@ -252,6 +291,8 @@ pub(super) fn TickLabels<X: Clone + 'static, Y: Clone + 'static>(
     bounds: Memo<Bounds>,
     state: State<X, Y>,
 ) -> impl IntoView {
+    // Project against our Y axis
+    let state = state.for_y_axis(ticks.y_axis);
     let ticks = move || {
         // Align vertical labels
         let ticks = ticks.ticks.get();
@@ -55,30 +55,46 @@ mod aspect_ratio;
 mod bounds;
 mod chart;
 mod colours;
+mod context;
 mod debug;
 mod edge;
+mod histogram;
 mod inner;
 mod layout;
 mod overlay;
 mod padding;
+#[cfg(all(feature = "png", not(target_arch = "wasm32")))]
+mod png;
 mod projection;
 mod series;
 mod state;
+mod svg_string;
+mod sync;
 mod ticks;
 mod use_watched_node;
 
 pub use aspect_ratio::AspectRatio;
+pub use bounds::Bounds;
 pub use chart::Chart;
 pub use colours::{Colour, ColourScheme, DivergingGradient, SequentialGradient};
+pub use context::{ChartContext, EdgeContext};
 pub use edge::Edge;
+pub use histogram::{Bin, Binning, Histogram};
 pub use inner::{
     axis_marker::{AxisMarker, AxisPlacement, AXIS_MARKER_COLOUR},
+    band::{XBand, YBand, BAND_COLOUR, BAND_OPACITY},
+    brush::{XBrush, BRUSH_COLOUR},
+    callout::{Callouts, CALLOUT_BACKGROUND_COLOUR, CALLOUT_COLOUR},
+    custom::CustomInner,
     grid_line::{XGridLine, YGridLine, GRID_LINE_COLOUR},
     guide_line::{AlignOver, XGuideLine, YGuideLine, GUIDE_LINE_COLOUR},
     legend::InsetLegend,
+    reference_line::{Dash, XReferenceLine, YReferenceLine, REFERENCE_LINE_COLOUR},
+    zoom::{Zoom, ZoomAxes, ZoomDrag, ZOOM_COLOUR},
     InnerLayout, IntoInner,
 };
 pub use layout::{
+    custom::CustomEdge,
     legend::Legend,
     rotated_label::{Anchor, RotatedLabel},
     tick_labels::TickLabels,
@@ -86,8 +102,20 @@ pub use layout::{
 };
 pub use overlay::tooltip::{Tooltip, TooltipPlacement, TooltipSortBy, TOOLTIP_CURSOR_DISTANCE};
 pub use padding::Padding;
+#[cfg(all(feature = "png", not(target_arch = "wasm32")))]
+pub use png::render_png;
+pub use projection::Scale;
 pub use series::{
-    Bar, BarPlacement, Interpolation, Line, Marker, MarkerShape, Series, Stack, Step, BAR_GAP,
-    BAR_GAP_INNER, DIVERGING_GRADIENT, LINEAR_GRADIENT, SERIES_COLOUR_SCHEME, STACK_COLOUR_SCHEME,
+    Bar, BarOrientation, BarPlacement, BoxPlot, Candlestick, DataPoint, ErrorBars, ErrorStyle,
+    Fill, FillBaseline, Interpolation, Line, Marker, MarkerShape, Scatter, Series, Stack, Step,
+    YAxis, BAR_GAP, BAR_GAP_INNER, CANDLESTICK_FALLING_COLOUR, CANDLESTICK_RISING_COLOUR,
+    DIVERGING_GRADIENT, ERROR_BAND_OPACITY, ERROR_CAP_WIDTH, FILL_OPACITY, LINEAR_GRADIENT,
+    SERIES_COLOUR_SCHEME, STACK_COLOUR_SCHEME,
+};
+#[cfg(not(target_arch = "wasm32"))]
+pub use svg_string::render_svg_string;
+pub use sync::SyncGroup;
+pub use ticks::{
+    AlignedFloats, AlignedIntegers, BinEdges, Categories, Category, CategoryTicks, LogFloats,
+    Period, Tick, Timestamps,
 };
-pub use ticks::{AlignedFloats, Period, Tick, Timestamps};
@@ -269,12 +269,22 @@ pub(crate) fn Tooltip<X: Tick, Y: Tick>(
         })
     };
 
+    let nearest_extra_y = state.pre.data.nearest_extra_y(state.hover_position_x);
     let nearest_data_y = move || {
         nearest_y_values
             .get()
             .into_iter()
             .map(|(line, y_value)| {
-                let y_value = format_y_value(y_value);
+                // Extra values e.g., a candlestick's open, high and low
+                let extra = nearest_extra_y.with(|extra| {
+                    extra.get(&line.id).map_or_else(Vec::new, |extra| {
+                        extra
+                            .iter()
+                            .map(|y| format_y_value(Some(y.clone())))
+                            .collect::<Vec<_>>()
+                    })
+                });
+                let y_value = line.format_values(format_y_value(y_value), &extra);
                 (line, y_value)
             })
             .collect::<Vec<_>>()
@@ -282,9 +292,12 @@ pub(crate) fn Tooltip<X: Tick, Y: Tick>(
 
     let series_tr = {
         let state = state.clone();
+        let hover_series = state.hover_series;
         move |(series, y_value): (UseY, String)| {
+            let id = series.id;
+            let font_weight = move || (hover_series.get() == Some(id)).then_some("bold");
             view! {
-                <tr>
+                <tr style:font-weight=font_weight>
                     <td><Snippet series=series state=state.clone() /></td>
                     <td
                         style="white-space: pre; font-family: monospace; text-align: right;"
@@ -298,7 +311,7 @@ pub(crate) fn Tooltip<X: Tick, Y: Tick>(
     };
 
     view! {
-        <Show when=move || state.hover_inner.get() && placement.get() != TooltipPlacement::Hide>
+        <Show when=move || state.hover_any.get() && placement.get() != TooltipPlacement::Hide>
             <DebugRect label="tooltip" debug=debug />
             <aside
                 class="_chartistry_tooltip"
@@ -0,0 +1,104 @@
+use crate::render_svg_string;
+use leptos::IntoView;
+use resvg::{tiny_skia, usvg};
+
+/// Monospace font used for all text. Bundled so output doesn't depend on the system's fonts.
+const FONT: &[u8] = include_bytes!("../fonts/DejaVuSansMono.ttf");
+const FONT_FAMILY: &str = "DejaVu Sans Mono";
+
+/// Renders a [Chart](crate::Chart) to PNG bytes without a browser. Useful for thumbnails and attachments generated by a batch job. Requires the `png` feature and is not available on `wasm32` targets.
+///
+/// The chart is first rendered to SVG at the given width and height (see [render_svg_string]) and then rasterised. Use `scale` to change the size of the image e.g., 2.0 for high DPI screens or 0.5 for a thumbnail.
+///
+/// All text uses a bundled monospace font (DejaVu Sans Mono) whose characters are 0.6 of the font height wide. The default `font_height` (16) and `font_width` (10) fit this font. If you change one, keep the other in proportion.
+///
+/// Components rendered as HTML inside a `<foreignObject>` are not rasterised. This includes [Legend](struct@crate::Legend) and [InsetLegend](crate::InsetLegend). Use SVG (e.g., a [CustomEdge](crate::CustomEdge) with `<text>`) instead.
+///
+/// ```rust
+/// use leptos::*;
+/// use leptos_chartistry::*;
+///
+/// let png = render_png(600.0, 300.0, 1.0, || {
+///     let data = Signal::derive(|| vec![(0.0, 1.0), (1.0, 3.0), (2.0, 2.0)]);
+///     view! {
+///         <Chart
+///             aspect_ratio=AspectRatio::from_env()
+///             left=TickLabels::aligned_floats()
+///             bottom=TickLabels::aligned_floats()
+///             series=Series::new(|&(x, _): &(f64, f64)| x).line(|&(_, y): &(f64, f64)| y)
+///             data=data
+///         />
+///     }
+/// })
+/// .unwrap();
+/// assert!(png.starts_with(b"\x89PNG"));
+/// ```
+pub fn render_png<F, N>(width: f64, height: f64, scale: f32, chart: F) -> Result<Vec<u8>, String>
+where
+    F: FnOnce() -> N + 'static,
+    N: IntoView,
+{
+    let svg = render_svg_string(width, height, chart);
+    svg_to_png(&svg, scale)
+}
+
+/// Rasterises an SVG string to PNG bytes using the bundled font.
+fn svg_to_png(svg: &str, scale: f32) -> Result<Vec<u8>, String> {
+    let mut options = usvg::Options {
+        font_family: FONT_FAMILY.to_string(),
+        ..usvg::Options::default()
+    };
+    let fonts = options.fontdb_mut();
+    fonts.load_font_data(FONT.to_vec());
+    fonts.set_monospace_family(FONT_FAMILY);
+    fonts.set_sans_serif_family(FONT_FAMILY);
+    fonts.set_serif_family(FONT_FAMILY);
+
+    let tree = usvg::Tree::from_str(svg, &options).map_err(|err| err.to_string())?;
+    let size = tree
+        .size()
+        .to_int_size()
+        .scale_by(scale)
+        .ok_or_else(|| format!("invalid PNG size: {scale} scale of {:?}", tree.size()))?;
+    let mut pixmap = tiny_skia::Pixmap::new(size.width(), size.height())
+        .ok_or_else(|| format!("invalid PNG size: {size:?}"))?;
+    // Charts have a transparent background. Use white like a typical page
+    pixmap.fill(tiny_skia::Color::WHITE);
+    let transform = tiny_skia::Transform::from_scale(scale, scale);
+    resvg::render(&tree, transform, &mut pixmap.as_mut());
+    pixmap.encode_png().map_err(|err| err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SVG: &str = r#"<svg xmlns="http://www.w3.org/2000/svg" width="40px" height="20px" viewBox="0 0 40 20"><text x="0" y="16" font-family="monospace" font-size="16">ab</text></svg>"#;
+
+    fn png_size(png: &[u8]) -> (u32, u32) {
+        let int = |at: usize| u32::from_be_bytes(png[at..at + 4].try_into().unwrap());
+        (int(16), int(20))
+    }
+
+    #[test]
+    fn test_svg_to_png() {
+        let png = svg_to_png(SVG, 1.0).unwrap();
+        assert!(png.starts_with(b"\x89PNG"));
+        assert_eq!(png_size(&png), (40, 20));
+        // Scaled
+        let png = svg_to_png(SVG, 0.5).unwrap();
+        assert_eq!(png_size(&png), (20, 10));
+        // Invalid
+        assert!(svg_to_png("<svg", 1.0).is_err());
+        assert!(svg_to_png(SVG, 0.0).is_err());
+    }
+
+    #[test]
+    fn test_bundled_font() {
+        let mut fonts = resvg::usvg::fontdb::Database::new();
+        fonts.load_font_data(FONT.to_vec());
+        let face = fonts.faces().next().unwrap();
+        assert_eq!(face.families[0].0, FONT_FAMILY);
+        assert!(face.monospaced);
+    }
+}
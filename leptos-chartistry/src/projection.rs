@@ -1,5 +1,18 @@
 use crate::bounds::Bounds;
 
+/// How data positions are mapped on to an axis.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub enum Scale {
+    /// Positions are spaced evenly.
+    #[default]
+    Linear,
+    /// Positions are mapped through log10. Useful for data spanning several orders of magnitude. Values less than or equal to zero are missing (not shown).
+    Log10,
+    /// Symmetric log. Like [Scale::Log10] but handles zero and negative values by being linear around zero: `sign(v) * log10(1 + |v|)`.
+    SymLog,
+}
+
 /// A projection converts between data and SVG coordinates. SVG has zero in the top left corner. Data coordinates have zero in the bottom left.
 #[derive(Clone, Debug, PartialEq)]
 pub struct Projection {
@@ -11,12 +24,32 @@ pub struct Projection {
 
     x_mult: f64,
     y_mult: f64,
+
+    scale_x: Scale,
+    scale_y: Scale,
 }
 
 impl Projection {
-    pub fn new(bounds: Bounds, range_x: Option<(f64, f64)>, range_y: Option<(f64, f64)>) -> Self {
-        let (left_x, right_x) = range_x.unwrap_or_default();
-        let (bottom_y, top_y) = range_y.unwrap_or_default();
+    /// Creates a linear projection.
+    #[cfg(test)]
+    pub(crate) fn new(
+        bounds: Bounds,
+        range_x: Option<(f64, f64)>,
+        range_y: Option<(f64, f64)>,
+    ) -> Self {
+        Self::scaled(bounds, range_x, range_y, Scale::Linear, Scale::Linear)
+    }
+
+    /// Creates a projection that maps data through the given scales.
+    pub fn scaled(
+        bounds: Bounds,
+        range_x: Option<(f64, f64)>,
+        range_y: Option<(f64, f64)>,
+        scale_x: Scale,
+        scale_y: Scale,
+    ) -> Self {
+        let (left_x, right_x) = scale_x.apply_range(range_x.unwrap_or_default());
+        let (bottom_y, top_y) = scale_y.apply_range(range_y.unwrap_or_default());
         // If the range is zero, skip projection
         let width = right_x - left_x;
         let x_mult = bounds.width() / if width == 0.0 { 0.5 } else { width };
@@ -28,11 +61,20 @@ impl Projection {
             bottom_y,
             x_mult,
             y_mult,
+            scale_x,
+            scale_y,
         }
     }
 
+    /// Returns the SVG bounds that the data range is projected on to.
+    pub fn bounds(&self) -> Bounds {
+        self.bounds
+    }
+
     /// Converts a data point to SVG view coordinates. View coordinates are in SVG space with zero at top left. Data coordinates are in chart space with zero at bottom left.
     pub fn position_to_svg(&self, x: f64, y: f64) -> (f64, f64) {
+        let x = self.scale_x.apply(x);
+        let y = self.scale_y.apply(y);
         let x = self.bounds.left_x() + (x - self.left_x) * self.x_mult;
         let y = self.bounds.bottom_y() - (y - self.bottom_y) * self.y_mult;
         (x, y)
@@ -42,10 +84,82 @@ impl Projection {
     pub fn svg_to_position(&self, x: f64, y: f64) -> (f64, f64) {
         let x = self.left_x + (x - self.bounds.left_x()) / self.x_mult;
         let y = self.bottom_y - (y - self.bounds.bottom_y()) / self.y_mult;
+        (self.scale_x.invert(x), self.scale_y.invert(y))
+    }
+
+    /// Returns the SVG coordinates of the zero position. Falls back to the bottom left when zero can't be shown (e.g., on a log scale).
+    pub fn svg_zero(&self) -> (f64, f64) {
+        let (x, y) = self.position_to_svg(0.0, 0.0);
+        let x = if x.is_finite() {
+            x
+        } else {
+            self.bounds.left_x()
+        };
+        let y = if y.is_finite() {
+            y
+        } else {
+            self.bounds.bottom_y()
+        };
         (x, y)
     }
 }
 
+impl Scale {
+    /// Maps a data position on to the scale.
+    pub(crate) fn apply(self, value: f64) -> f64 {
+        match self {
+            Scale::Linear => value,
+            Scale::Log10 if value <= 0.0 => f64::NAN,
+            Scale::Log10 => value.log10(),
+            Scale::SymLog => value.signum() * value.abs().ln_1p() / std::f64::consts::LN_10,
+        }
+    }
+
+    /// Inverse of [Scale::apply].
+    pub(crate) fn invert(self, value: f64) -> f64 {
+        match self {
+            Scale::Linear => value,
+            Scale::Log10 => 10_f64.powf(value),
+            Scale::SymLog => value.signum() * (10_f64.powf(value.abs()) - 1.0),
+        }
+    }
+
+    /// Maps a range on to the scale. Missing bounds are replaced with a decade from the other bound e.g., a log scale where nothing is above zero. Data ranges pick a positive minimum for log scales with `Range::positions_for`.
+    fn apply_range(self, (first, last): (f64, f64)) -> (f64, f64) {
+        let first = self.apply(first);
+        let last = self.apply(last);
+        match (first.is_finite(), last.is_finite()) {
+            (true, true) => (first, last),
+            (false, true) => (last - 1.0, last),
+            (true, false) => (first, first + 1.0),
+            (false, false) => (0.0, 1.0),
+        }
+    }
+}
+
+impl std::str::FromStr for Scale {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "linear" => Ok(Scale::Linear),
+            "log10" => Ok(Scale::Log10),
+            "symlog" => Ok(Scale::SymLog),
+            _ => Err("unknown scale"),
+        }
+    }
+}
+
+impl std::fmt::Display for Scale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Scale::Linear => write!(f, "Linear"),
+            Scale::Log10 => write!(f, "Log10"),
+            Scale::SymLog => write!(f, "SymLog"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -86,6 +200,59 @@ mod tests {
         Projection::new(bounds, None, None);
     }
 
+    #[test]
+    fn test_log10() {
+        let bounds = Bounds::from_points(10.0, 10.0, 90.0, 90.0);
+        let p = Projection::scaled(
+            bounds,
+            Some((0.0, 100.0)),
+            Some((1.0, 10_000.0)),
+            Scale::Linear,
+            Scale::Log10,
+        );
+        assert_coords(&p, (0.0, 1.0), (10.0, 90.0)); // Bottom left
+        assert_coords(&p, (100.0, 10_000.0), (90.0, 10.0)); // Top right
+        assert_coords(&p, (50.0, 100.0), (50.0, 50.0)); // Centre
+
+        // Zero and below are missing
+        assert!(p.position_to_svg(0.0, 0.0).1.is_nan());
+        assert!(p.position_to_svg(0.0, -1.0).1.is_nan());
+        assert_eq!(p.svg_zero(), (10.0, 90.0));
+    }
+
+    #[test]
+    fn test_log10_zero_range() {
+        let bounds = Bounds::from_points(10.0, 10.0, 90.0, 90.0);
+        // Lower bound of zero becomes a decade below the upper bound
+        let p = Projection::scaled(
+            bounds,
+            None,
+            Some((0.0, 100.0)),
+            Scale::Linear,
+            Scale::Log10,
+        );
+        assert_eq!(p.position_to_svg(0.0, 10.0).1, 90.0);
+        assert_eq!(p.position_to_svg(0.0, 100.0).1, 10.0);
+    }
+
+    #[test]
+    fn test_symlog() {
+        let bounds = Bounds::from_points(0.0, 0.0, 100.0, 100.0);
+        let p = Projection::scaled(
+            bounds,
+            Some((-99.0, 99.0)),
+            None,
+            Scale::SymLog,
+            Scale::Linear,
+        );
+        assert_eq!(p.position_to_svg(0.0, 0.0).0, 50.0);
+        assert_eq!(p.position_to_svg(-99.0, 0.0).0, 0.0);
+        assert_eq!(p.position_to_svg(99.0, 0.0).0, 100.0);
+        assert!((p.position_to_svg(9.0, 0.0).0 - 75.0).abs() < 1e-9);
+        let (x, _) = p.svg_to_position(75.0, 0.0);
+        assert!((x - 9.0).abs() < 1e-9);
+    }
+
     #[test]
     fn test_partial_eq() {
         let bounds = Bounds::from_points(10.0, 10.0, 90.0, 90.0);
@@ -1,4 +1,7 @@
-use super::{ApplyUseSeries, GetYValue, IntoUseBar, SeriesAcc, UseY};
+use super::{
+    error_bars::{GetError, RenderWhiskers},
+    ApplyUseSeries, ErrorBars, GetYValue, IntoUseBar, SeriesAcc, UseY, YAxis,
+};
 use crate::{state::State, Colour, Tick};
 use leptos::*;
 use std::rc::Rc;
@@ -20,6 +23,30 @@ pub const BAR_GAP_INNER: f64 = 0.05;
 ///     .bar(|data: &MyData| data.y2);
 /// ```
 /// See this in action with a [full bar chart example](https://feral-dot-io.github.io/leptos-chartistry/examples.html#bar-chart).
+///
+/// Bars can also be stacked on top of each other with [Stack::bar](crate::Stack::bar).
+///
+/// # Horizontal bars
+/// Set the [orientation](BarOrientation) to draw bars along the X axis. Each bar is placed at its Y value and extends to the X value. This suits ranked lists with long labels:
+/// ```rust
+/// # use leptos_chartistry::*;
+/// # struct MyData { latency: f64, rank: f64 }
+/// let series = Series::new(|data: &MyData| data.latency)
+///     .bar(Bar::new(|data: &MyData| data.rank).with_orientation(BarOrientation::Horizontal));
+/// ```
+/// See this in action with the [horizontal bar chart example](https://feral-dot-io.github.io/leptos-chartistry/examples.html#horizontal-bar-chart).
+///
+/// # Error bars
+/// Vertical bars can show lower and upper values as whiskers. Give them a contrasting colour as the lower half is otherwise hidden by the bar:
+/// ```rust
+/// # use leptos_chartistry::*;
+/// # struct MyData { x: f64, mean: f64, low: f64, high: f64 }
+/// let series = Series::new(|data: &MyData| data.x).bar(
+///     Bar::new(|data: &MyData| data.mean)
+///         .with_error(|data: &MyData| data.low, |data: &MyData| data.high)
+///         .with_error_bars(ErrorBars::default().with_colour(Colour::from_rgb(0, 0, 0))),
+/// );
+/// ```
 #[non_exhaustive]
 pub struct Bar<T, Y> {
     get_y: Rc<dyn GetYValue<T, Y>>,
@@ -29,6 +56,8 @@ pub struct Bar<T, Y> {
     pub colour: RwSignal<Option<Colour>>,
     /// Sets where the bar's bottom is placed. Defaults to the zero line.
     pub placement: RwSignal<BarPlacement>,
+    /// Sets the direction the bar extends in. Defaults to [vertical](BarOrientation::Vertical).
+    pub orientation: RwSignal<BarOrientation>,
     /// Set the gap between group bars. Clamped to 0.0 and 1.0. Defaults to 0.1.
     ///
     /// The gap is the ratio of the available width for an X value. For example if the chart has a width of 200px and 5 items (`T`) that leaves 40px per item. So a gap of 0.1 (10%) would leave 4px between each item with 2px on either side.
@@ -37,6 +66,10 @@ pub struct Bar<T, Y> {
     ///
     /// The group gap is the ratio of the available width for a single bar in a group of bars (for a single X value). Carrying on the example from [gap](Self::gap) a group gap of 0.05 (5%) and two bars would result in 1px (40 / 2 * 0.05). This group gap becomes the space inbetween each bar.
     pub group_gap: RwSignal<f64>,
+    /// How the lower and upper values set by [Bar::with_error] are drawn. Bars always use whiskers.
+    pub error_bars: ErrorBars,
+    /// Which Y axis the bar is projected against. Default is [YAxis::Primary].
+    pub y_axis: RwSignal<YAxis>,
 }
 
 /// The location of where the bar extends from.
@@ -50,13 +83,27 @@ pub enum BarPlacement {
     Edge,
 }
 
+/// The direction a bar extends in.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub enum BarOrientation {
+    /// The bar is placed at an X value and extends along the Y axis.
+    #[default]
+    Vertical,
+    /// The bar is placed at a Y value and extends along the X axis. The [placement](BarPlacement) is then the zero line or left edge. Horizontal bars cannot be stacked.
+    Horizontal,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct UseBar {
     group_id: usize,
+    stacked_on: Option<usize>,
     colour: Signal<Colour>,
     placement: RwSignal<BarPlacement>,
+    orientation: RwSignal<BarOrientation>,
     gap: RwSignal<f64>,
     group_gap: RwSignal<f64>,
+    error_bars: ErrorBars,
 }
 
 impl<T, Y> Bar<T, Y> {
@@ -72,8 +119,11 @@ impl<T, Y> Bar<T, Y> {
             name: RwSignal::default(),
             colour: RwSignal::default(),
             placement: RwSignal::default(),
+            orientation: RwSignal::default(),
             gap: create_rw_signal(BAR_GAP),
             group_gap: create_rw_signal(BAR_GAP_INNER),
+            error_bars: ErrorBars::default(),
+            y_axis: RwSignal::default(),
         }
     }
 
@@ -95,6 +145,12 @@ impl<T, Y> Bar<T, Y> {
         self
     }
 
+    /// Set the orientation of the bar.
+    pub fn with_orientation(self, orientation: impl Into<BarOrientation>) -> Self {
+        self.orientation.set(orientation.into());
+        self
+    }
+
     /// Set the gap between a group of bars. Clamped to 0.0 and 1.0. Defaults to 0.1.
     pub fn with_gap(self, gap: f64) -> Self {
         self.gap.set(gap);
@@ -106,6 +162,64 @@ impl<T, Y> Bar<T, Y> {
         self.group_gap.set(group_gap);
         self
     }
+
+    /// Set lower and upper values drawn as whiskers on the bar e.g., a standard error. Included in the Y range. Ignored for horizontal and stacked bars.
+    pub fn with_error(
+        mut self,
+        lower: impl Fn(&T) -> Y + 'static,
+        upper: impl Fn(&T) -> Y + 'static,
+    ) -> Self
+    where
+        T: 'static,
+        Y: 'static,
+    {
+        self.get_y = Rc::new(GetError {
+            get_y: self.get_y,
+            lower: Rc::new(lower),
+            upper: Rc::new(upper),
+        });
+        self
+    }
+
+    /// Set how the lower and upper values are drawn.
+    pub fn with_error_bars(mut self, error_bars: impl Into<ErrorBars>) -> Self {
+        self.error_bars = error_bars.into();
+        self
+    }
+
+    /// Set which Y axis the bar is projected against.
+    pub fn with_y_axis(self, y_axis: impl Into<YAxis>) -> Self {
+        self.y_axis.set(y_axis.into());
+        self
+    }
+
+    /// Converts to a [UseBar] that sits on top of another series (by ID) instead of its placement. Used by a stack.
+    pub(super) fn into_use_bar_stacked_on(
+        self,
+        id: usize,
+        group_id: usize,
+        colour: Memo<Colour>,
+        stacked_on: Option<usize>,
+    ) -> (UseY, Rc<dyn GetYValue<T, Y>>) {
+        let override_colour = self.colour;
+        let colour = Signal::derive(move || override_colour.get().unwrap_or(colour.get()));
+        let bar = UseY::new_bar(
+            id,
+            self.name,
+            self.y_axis,
+            UseBar {
+                group_id,
+                stacked_on,
+                colour,
+                placement: self.placement,
+                orientation: self.orientation,
+                gap: self.gap,
+                group_gap: self.group_gap,
+                error_bars: self.error_bars.clone(),
+            },
+        );
+        (bar, self.get_y.clone())
+    }
 }
 
 impl<T, Y> Clone for Bar<T, Y> {
@@ -113,10 +227,13 @@ impl<T, Y> Clone for Bar<T, Y> {
         Self {
             get_y: self.get_y.clone(),
             placement: self.placement,
+            orientation: self.orientation,
             gap: self.gap,
             group_gap: self.group_gap,
+            error_bars: self.error_bars.clone(),
             name: self.name,
             colour: self.colour,
+            y_axis: self.y_axis,
         }
     }
 }
@@ -141,21 +258,100 @@ impl<T, Y> IntoUseBar<T, Y> for Bar<T, Y> {
         group_id: usize,
         colour: Memo<Colour>,
     ) -> (UseY, Rc<dyn GetYValue<T, Y>>) {
-        let override_colour = self.colour;
-        let colour = Signal::derive(move || override_colour.get().unwrap_or(colour.get()));
-        let bar = UseY::new_bar(
-            id,
-            self.name,
-            UseBar {
-                group_id,
-                colour,
-                placement: self.placement,
-                gap: self.gap,
-                group_gap: self.group_gap,
-            },
-        );
-        (bar, self.get_y.clone())
+        self.into_use_bar_stacked_on(id, group_id, colour, None)
+    }
+}
+
+impl UseBar {
+    pub(super) fn orientation(&self) -> BarOrientation {
+        self.orientation.get()
+    }
+
+    pub(super) fn group_id(&self) -> usize {
+        self.group_id
     }
+
+    /// SVG positions of the series this bar is stacked on (if any).
+    pub(super) fn stacked_on<X: Tick, Y: Tick>(
+        &self,
+        state: &State<X, Y>,
+    ) -> Option<Signal<Vec<(f64, f64)>>> {
+        let data = state.pre.data.clone();
+        let projection = state.projection;
+        self.stacked_on
+            .map(|id| Signal::derive(move || data.svg_positions(id, &projection.get())))
+    }
+}
+
+/// Returns the number of groups with an orientation and the index of a group among them. Groups are ordered by ID.
+fn group_index(
+    groups: impl Iterator<Item = (BarOrientation, usize)>,
+    orientation: BarOrientation,
+    group_id: usize,
+) -> (usize, usize) {
+    let mut groups = groups
+        .filter(|&(group_orientation, _)| group_orientation == orientation)
+        .map(|(_, id)| id)
+        .collect::<Vec<_>>();
+    groups.sort_unstable();
+    groups.dedup();
+    let index = groups.iter().position(|&id| id == group_id);
+    (groups.len().max(1), index.unwrap_or_default())
+}
+
+/// Returns the offset (from the X or Y position) and width of a group within a position of the given width. `groups` share the position and this group is at `index`.
+fn group_slot(width: f64, gap: f64, group_gap: f64, (groups, index): (usize, usize)) -> (f64, f64) {
+    // Find width of each X (or Y) position
+    let width = width * (1.0 - gap.clamp(0.0, 1.0));
+    // Find width of each group in an X (or Y) position
+    let group_gap = group_gap.clamp(0.0, 1.0);
+    let group_width = width / groups as f64;
+    let group_width_inner = group_width * (1.0 - group_gap);
+    let group_gap = group_width * group_gap;
+
+    let offset = group_gap / 2.0 - width / 2.0 + group_width * index as f64;
+    (offset, group_width_inner)
+}
+
+/// Returns the SVG rect (x, y, width, height) of a bar at a position extending to its base.
+fn bar_rect(
+    orientation: BarOrientation,
+    (x, y): (f64, f64),
+    base: f64,
+    (offset, width): (f64, f64),
+) -> (f64, f64, f64, f64) {
+    match orientation {
+        BarOrientation::Vertical => (x + offset, y, width, base - y),
+        BarOrientation::Horizontal => (base.min(x), y + offset, (x - base).abs(), width),
+    }
+}
+
+/// Returns the offset (from the X or Y position) and width of a group within each X (or Y) position. Bars, candlesticks and box plots of the same orientation share each position with one group each. Stacked bars share a group.
+pub(super) fn use_group_slot<X: 'static, Y: 'static>(
+    state: &State<X, Y>,
+    orientation: Signal<BarOrientation>,
+    gap: RwSignal<f64>,
+    group_gap: RwSignal<f64>,
+    group_id: usize,
+) -> Memo<(f64, f64)> {
+    // Number of visible groups and our index
+    let visible = state.pre.data.visible;
+    let groups = create_memo(move |_| {
+        visible.with(|series| {
+            let groups = series.iter().filter_map(|series| series.slot_group());
+            group_index(groups, orientation.get(), group_id)
+        })
+    });
+
+    let (x_width, y_width) = (state.layout.x_width, state.layout.y_width);
+    create_memo(move |_| {
+        // Note: this should possibly be on Layout
+        let width = match orientation.get() {
+            BarOrientation::Vertical => x_width.get(),
+            BarOrientation::Horizontal => y_width.get(),
+        };
+        group_slot(width, gap.get(), group_gap.get(), groups.get())
+    })
 }
 
 #[component]
@@ -163,57 +359,130 @@ pub fn RenderBar<X: 'static, Y: 'static>(
     bar: UseBar,
     state: State<X, Y>,
     positions: Signal<Vec<(f64, f64)>>,
+    stacked_on: Option<Signal<Vec<(f64, f64)>>>,
+    /// SVG lower and upper positions of error bars. Empty if none.
+    #[prop(optional)]
+    error: Signal<Vec<Vec<f64>>>,
 ) -> impl IntoView {
-    let bars = create_memo(move |_| {
-        state
-            .pre
-            .data
-            .series
-            .get()
-            .iter()
-            .filter_map(|series| series.bar().map(|bar| (series.clone(), bar.clone())))
-            .collect::<Vec<_>>()
-            .len()
-    });
+    let orientation = bar.orientation;
+    let slot = use_group_slot(
+        &state,
+        Signal::derive(move || orientation.get()),
+        bar.gap,
+        bar.group_gap,
+        bar.group_id,
+    );
 
     let rects = move || {
         positions.with(|positions| {
-            // Find the bottom Y position of each bar
-            let bottom_y = match bar.placement.get() {
-                BarPlacement::Zero => state.svg_zero.get().1,
-                BarPlacement::Edge => state.layout.inner.get().bottom_y(),
+            let orientation = bar.orientation.get();
+            let inner = state.layout.inner.get();
+            let svg_zero = state.svg_zero.get();
+            // Find the base of each bar
+            let base = match (orientation, bar.placement.get()) {
+                (BarOrientation::Vertical, BarPlacement::Zero) => svg_zero.1,
+                (BarOrientation::Vertical, BarPlacement::Edge) => inner.bottom_y(),
+                (BarOrientation::Horizontal, BarPlacement::Zero) => svg_zero.0,
+                (BarOrientation::Horizontal, BarPlacement::Edge) => inner.left_x(),
             };
 
-            // Find width of each X position
-            // Note: this should possibly be on Layout
-            let gap = bar.gap.get().clamp(0.0, 1.0);
-            let width = state.layout.x_width.get() * (1.0 - gap);
-            // Find width of each group in an X position
-            let group_gap = bar.group_gap.get().clamp(0.0, 1.0);
-            let group_width = width / bars.get() as f64;
-            let group_width_inner = group_width * (1.0 - group_gap);
-            let group_gap = group_width * group_gap;
-
-            let offset = group_gap / 2.0 - width / 2.0;
+            let slot = slot.get();
+            let bottoms = stacked_on.map(|stacked_on| stacked_on.get());
             positions
                 .iter()
-                .map(|&(x, y)| {
+                .enumerate()
+                .map(|(index, &position)| {
+                    // Stacked bars start from the top of the previous bar
+                    let base = bottoms
+                        .as_ref()
+                        .and_then(|bottoms| bottoms.get(index))
+                        .map(|&(_, bottom_y)| bottom_y)
+                        .filter(|bottom_y| !bottom_y.is_nan())
+                        .unwrap_or(base);
+                    let (x, y, width, height) = bar_rect(orientation, position, base, slot);
                     view! {
-                        <rect
-                            x=x + group_width * bar.group_id as f64 + offset
-                            y=y
-                            width=group_width_inner
-                            height=bottom_y - y />
+                        <rect x=x y=y width=width height=height />
                     }
                 })
                 .collect::<Vec<_>>()
         })
     };
+
+    // Error bars on the centre of each vertical bar
+    let whisker_positions = Signal::derive(move || {
+        if bar.orientation.get() != BarOrientation::Vertical {
+            return Vec::new();
+        }
+        let (offset, group_width_inner) = slot.get();
+        let centre = offset + group_width_inner / 2.0;
+        positions.with(|positions| {
+            positions
+                .iter()
+                .map(|&(x, y)| (x + centre, y))
+                .collect::<Vec<_>>()
+        })
+    });
+    let colour = Signal::derive(move || bar.colour.get().to_string());
+
     view! {
         <g
             class="_chartistry_bar"
             fill=move || bar.colour.get().to_string()>
             {rects}
+            <RenderWhiskers
+                error_bars=bar.error_bars.clone()
+                colour=colour
+                positions=whisker_positions
+                extra=error />
         </g>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use BarOrientation::*;
+
+    #[test]
+    fn test_group_index() {
+        let groups = [(Vertical, 0), (Horizontal, 1), (Vertical, 2), (Vertical, 2)];
+        let index = |orientation, group_id| group_index(groups.into_iter(), orientation, group_id);
+        // Mixed orientations: vertical groups skip horizontal ones
+        assert_eq!(index(Vertical, 0), (2, 0));
+        assert_eq!(index(Vertical, 2), (2, 1));
+        assert_eq!(index(Horizontal, 1), (1, 0));
+        // No groups
+        assert_eq!(group_index([].into_iter(), Vertical, 0), (1, 0));
+    }
+
+    #[test]
+    fn test_group_slot() {
+        // One group fills the position less the gaps
+        assert_eq!(group_slot(100.0, 0.2, 0.0, (1, 0)), (-40.0, 80.0));
+        // Two groups split it
+        assert_eq!(group_slot(100.0, 0.2, 0.0, (2, 0)), (-40.0, 40.0));
+        assert_eq!(group_slot(100.0, 0.2, 0.0, (2, 1)), (0.0, 40.0));
+        assert_eq!(group_slot(100.0, 0.0, 0.5, (2, 1)), (12.5, 25.0));
+        // Clamped
+        assert_eq!(group_slot(100.0, 2.0, -1.0, (1, 0)), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_bar_rect() {
+        let slot = (-5.0, 10.0);
+        assert_eq!(
+            bar_rect(Vertical, (50.0, 20.0), 100.0, slot),
+            (45.0, 20.0, 10.0, 80.0)
+        );
+        // Horizontal bars extend from the base along the X axis
+        assert_eq!(
+            bar_rect(Horizontal, (80.0, 50.0), 20.0, slot),
+            (20.0, 45.0, 60.0, 10.0)
+        );
+        // ...in either direction
+        assert_eq!(
+            bar_rect(Horizontal, (10.0, 50.0), 20.0, slot),
+            (10.0, 45.0, 10.0, 10.0)
+        );
+    }
+}
@@ -0,0 +1,416 @@
+use super::{
+    bar::use_group_slot, ApplyUseSeries, GetYValue, IntoUseBoxPlot, SeriesAcc, UseY, YAxis,
+};
+use crate::{state::State, BarOrientation, Colour, Tick, BAR_GAP, BAR_GAP_INNER, FILL_OPACITY};
+use leptos::*;
+use std::rc::Rc;
+
+/// Radius of an outlier's marker relative to the box plot's line width.
+const OUTLIER_RADIUS: f64 = 2.5;
+
+/// Draws a box and whisker for each value on the chart from precomputed quartiles. The box spans the first to third quartile with a line at the median. Whiskers extend to the minimum and maximum. Outliers are optionally drawn as points beyond the whiskers.
+///
+/// # Example
+/// ```rust
+/// # use leptos_chartistry::*;
+/// # struct Release { version: f64, min: f64, q1: f64, median: f64, q3: f64, max: f64, outliers: Vec<f64> }
+/// let series = Series::new(|r: &Release| r.version).box_plot(
+///     BoxPlot::new(
+///         |r: &Release| r.min,
+///         |r: &Release| r.q1,
+///         |r: &Release| r.median,
+///         |r: &Release| r.q3,
+///         |r: &Release| r.max,
+///     )
+///     .with_outliers(|r: &Release| r.outliers.clone())
+///     .with_name("latency"),
+/// );
+/// ```
+/// See this in action with the [box plot example](https://feral-dot-io.github.io/leptos-chartistry/examples.html#box-plot).
+///
+/// Boxes are sized like a [Bar](crate::Bar) and share each X value with other bars and box plots. The median is used as the Y value e.g., for the nearest series and [DataPoint](crate::DataPoint). The tooltip shows the five quartile values.
+#[non_exhaustive]
+pub struct BoxPlot<T, Y> {
+    get_y: Rc<GetQuartiles<T, Y>>,
+    /// Name of the box plot. Used in the legend and tooltip.
+    pub name: RwSignal<String>,
+    /// Colour of the box plot. If not set, the next colour in the series will be used. Default is `None`.
+    pub colour: RwSignal<Option<Colour>>,
+    /// Gap between each X value. Clamped to 0.0 and 1.0. Default is [BAR_GAP]. See [Bar::gap](crate::Bar::gap) for details.
+    pub gap: RwSignal<f64>,
+    /// Gap between bars and box plots sharing an X value. Clamped to 0.0 and 1.0. Default is [BAR_GAP_INNER]. See [Bar::group_gap](crate::Bar::group_gap) for details.
+    pub group_gap: RwSignal<f64>,
+    /// Width of the box outline, median and whisker lines. Default is 1.0.
+    pub width: RwSignal<f64>,
+    /// Opacity of the box's fill. Clamped to 0.0 and 1.0. Default is [FILL_OPACITY].
+    pub opacity: RwSignal<f64>,
+    /// Which Y axis the box plot is projected against. Default is [YAxis::Primary].
+    pub y_axis: RwSignal<YAxis>,
+}
+
+type GetOutliers<T, Y> = Rc<dyn Fn(&T) -> Vec<Y>>;
+
+struct GetQuartiles<T, Y> {
+    min: Rc<dyn Fn(&T) -> Y>,
+    q1: Rc<dyn Fn(&T) -> Y>,
+    median: Rc<dyn Fn(&T) -> Y>,
+    q3: Rc<dyn Fn(&T) -> Y>,
+    max: Rc<dyn Fn(&T) -> Y>,
+    outliers: GetOutliers<T, Y>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UseBoxPlot {
+    group_id: usize,
+    colour: Signal<Colour>,
+    gap: RwSignal<f64>,
+    group_gap: RwSignal<f64>,
+    width: RwSignal<f64>,
+    opacity: RwSignal<f64>,
+}
+
+impl<T, Y> BoxPlot<T, Y> {
+    /// Create a new box plot. Each getter extracts a Y value (minimum, first quartile, median, third quartile and maximum) from your struct.
+    ///
+    /// See the module documentation for examples.
+    pub fn new(
+        min: impl Fn(&T) -> Y + 'static,
+        q1: impl Fn(&T) -> Y + 'static,
+        median: impl Fn(&T) -> Y + 'static,
+        q3: impl Fn(&T) -> Y + 'static,
+        max: impl Fn(&T) -> Y + 'static,
+    ) -> Self
+    where
+        Y: Tick,
+    {
+        Self {
+            get_y: Rc::new(GetQuartiles {
+                min: Rc::new(min),
+                q1: Rc::new(q1),
+                median: Rc::new(median),
+                q3: Rc::new(q3),
+                max: Rc::new(max),
+                outliers: Rc::new(|_| Vec::new()),
+            }),
+            name: RwSignal::default(),
+            colour: RwSignal::default(),
+            gap: create_rw_signal(BAR_GAP),
+            group_gap: create_rw_signal(BAR_GAP_INNER),
+            width: create_rw_signal(1.0),
+            opacity: create_rw_signal(FILL_OPACITY),
+            y_axis: RwSignal::default(),
+        }
+    }
+
+    /// Set outliers drawn as points beyond the whiskers. Included in the Y range.
+    pub fn with_outliers(mut self, outliers: impl Fn(&T) -> Vec<Y> + 'static) -> Self {
+        let get_y = &self.get_y;
+        self.get_y = Rc::new(GetQuartiles {
+            min: get_y.min.clone(),
+            q1: get_y.q1.clone(),
+            median: get_y.median.clone(),
+            q3: get_y.q3.clone(),
+            max: get_y.max.clone(),
+            outliers: Rc::new(outliers),
+        });
+        self
+    }
+
+    /// Set the name of the box plot. Used in the legend and tooltip.
+    pub fn with_name(self, name: impl Into<String>) -> Self {
+        self.name.set(name.into());
+        self
+    }
+
+    /// Set the colour of the box plot. If not set, the next colour in the series will be used.
+    pub fn with_colour(self, colour: impl Into<Option<Colour>>) -> Self {
+        self.colour.set(colour.into());
+        self
+    }
+
+    /// Set the gap between each X value. Clamped to 0.0 and 1.0.
+    pub fn with_gap(self, gap: impl Into<f64>) -> Self {
+        self.gap.set(gap.into());
+        self
+    }
+
+    /// Set the gap between bars and box plots sharing an X value. Clamped to 0.0 and 1.0.
+    pub fn with_group_gap(self, group_gap: impl Into<f64>) -> Self {
+        self.group_gap.set(group_gap.into());
+        self
+    }
+
+    /// Set the width of the box outline, median and whisker lines.
+    pub fn with_width(self, width: impl Into<f64>) -> Self {
+        self.width.set(width.into());
+        self
+    }
+
+    /// Set the opacity of the box's fill. Clamped to 0.0 and 1.0.
+    pub fn with_opacity(self, opacity: impl Into<f64>) -> Self {
+        self.opacity.set(opacity.into());
+        self
+    }
+
+    /// Set which Y axis the box plot is projected against.
+    pub fn with_y_axis(self, y_axis: impl Into<YAxis>) -> Self {
+        self.y_axis.set(y_axis.into());
+        self
+    }
+}
+
+impl<T, Y> Clone for BoxPlot<T, Y> {
+    fn clone(&self) -> Self {
+        Self {
+            get_y: self.get_y.clone(),
+            name: self.name,
+            colour: self.colour,
+            gap: self.gap,
+            group_gap: self.group_gap,
+            width: self.width,
+            opacity: self.opacity,
+            y_axis: self.y_axis,
+        }
+    }
+}
+
+impl<T, Y> GetYValue<T, Y> for GetQuartiles<T, Y> {
+    fn value(&self, t: &T) -> Y {
+        (self.median)(t)
+    }
+
+    fn cumulative_value(&self, t: &T) -> Y {
+        (self.median)(t)
+    }
+
+    fn extra_values(&self, t: &T) -> Vec<Y> {
+        let mut values = vec![(self.min)(t), (self.q1)(t), (self.q3)(t), (self.max)(t)];
+        values.extend((self.outliers)(t));
+        values
+    }
+}
+
+impl<T: 'static, Y: 'static> ApplyUseSeries<T, Y> for BoxPlot<T, Y> {
+    fn apply_use_series(self: Rc<Self>, series: &mut SeriesAcc<T, Y>) {
+        let colour = series.next_colour();
+        _ = series.push_box_plot(colour, (*self).clone());
+    }
+}
+
+impl<T: 'static, Y: 'static> IntoUseBoxPlot<T, Y> for BoxPlot<T, Y> {
+    fn into_use_box_plot(
+        self,
+        id: usize,
+        group_id: usize,
+        colour: Memo<Colour>,
+    ) -> (UseY, Rc<dyn GetYValue<T, Y>>) {
+        let override_colour = self.colour;
+        let colour = Signal::derive(move || override_colour.get().unwrap_or(colour.get()));
+        let box_plot = UseY::new_box_plot(
+            id,
+            self.name,
+            self.y_axis,
+            UseBoxPlot {
+                group_id,
+                colour,
+                gap: self.gap,
+                group_gap: self.group_gap,
+                width: self.width,
+                opacity: self.opacity,
+            },
+        );
+        (box_plot, self.get_y.clone())
+    }
+}
+
+impl UseBoxPlot {
+    pub(super) fn group_id(&self) -> usize {
+        self.group_id
+    }
+
+    /// Offset and width of each box within its X value. Shared with bars.
+    pub(super) fn use_slot<X: 'static, Y: 'static>(&self, state: &State<X, Y>) -> Memo<(f64, f64)> {
+        use_group_slot(
+            state,
+            Signal::derive(|| BarOrientation::Vertical),
+            self.gap,
+            self.group_gap,
+            self.group_id,
+        )
+    }
+
+    /// Formats the tooltip value from the (formatted) median and extra values.
+    pub(super) fn format_values(median: String, extra: &[String]) -> String {
+        match extra {
+            [min, q1, q3, max, ..] => format!("{min} | {q1} [{median}] {q3} | {max}"),
+            _ => median,
+        }
+    }
+}
+
+/// A box plot in SVG coordinates.
+#[derive(Clone, Debug, PartialEq)]
+struct BoxShape {
+    x: f64,
+    min: f64,
+    q1: f64,
+    median: f64,
+    q3: f64,
+    max: f64,
+    outliers: Vec<f64>,
+}
+
+impl BoxShape {
+    /// Returns the box shape of a position. None if any quartile is missing (NaN).
+    fn new(x: f64, median: f64, extra: &[f64]) -> Option<Self> {
+        let [min, q1, q3, max, outliers @ ..] = extra else {
+            return None;
+        };
+        let quartiles = [x, *min, *q1, median, *q3, *max];
+        if quartiles.iter().any(|v| v.is_nan()) {
+            return None;
+        }
+        Some(Self {
+            x,
+            min: *min,
+            q1: *q1,
+            median,
+            q3: *q3,
+            max: *max,
+            outliers: outliers.iter().copied().filter(|v| !v.is_nan()).collect(),
+        })
+    }
+
+    /// SVG path of the whiskers and their caps.
+    fn whiskers_path(&self, cap_width: f64) -> String {
+        let Self {
+            x,
+            min,
+            q1,
+            q3,
+            max,
+            ..
+        } = *self;
+        let (left, right) = (x - cap_width / 2.0, x + cap_width / 2.0);
+        format!(
+            "M {x} {q1} L {x} {min} M {left} {min} L {right} {min} M {x} {q3} L {x} {max} M {left} {max} L {right} {max}"
+        )
+    }
+}
+
+#[component]
+pub fn RenderBoxPlot(
+    box_plot: UseBoxPlot,
+    /// Offset and width of each box within its X value.
+    group_slot: Signal<(f64, f64)>,
+    positions: Signal<Vec<(f64, f64)>>,
+    extra: Signal<Vec<Vec<f64>>>,
+) -> impl IntoView {
+    let UseBoxPlot {
+        colour,
+        width: line_width,
+        opacity,
+        ..
+    } = box_plot;
+
+    let boxes = move || {
+        let (offset, width) = group_slot.get();
+        let line_width = line_width.get();
+        positions.with(|positions| {
+            extra.with(|extra| {
+                positions
+                    .iter()
+                    .zip(extra)
+                    .filter_map(|(&(x, median), extra)| {
+                        let shape = BoxShape::new(x + offset + width / 2.0, median, extra)?;
+                        let left = shape.x - width / 2.0;
+                        let outliers = shape
+                            .outliers
+                            .iter()
+                            .map(|&y| {
+                                view! {
+                                    <circle
+                                        cx=shape.x
+                                        cy=y
+                                        r=line_width * OUTLIER_RADIUS
+                                        fill="none" />
+                                }
+                            })
+                            .collect_view();
+                        Some(view! {
+                            <path d=shape.whiskers_path(width / 2.0) fill="none" />
+                            <rect
+                                x=left
+                                y=shape.q3.min(shape.q1)
+                                width=width
+                                height=(shape.q1 - shape.q3).abs()
+                                fill-opacity=move || opacity.get().clamp(0.0, 1.0) />
+                            <line
+                                x1=left
+                                y1=shape.median
+                                x2=left + width
+                                y2=shape.median />
+                            {outliers}
+                        })
+                    })
+                    .collect_view()
+            })
+        })
+    };
+
+    view! {
+        <g
+            class="_chartistry_box_plot"
+            fill=move || colour.get().to_string()
+            stroke=move || colour.get().to_string()
+            stroke-width=line_width>
+            {boxes}
+        </g>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_box_shape() {
+        let shape = BoxShape::new(5.0, 30.0, &[50.0, 40.0, 20.0, 10.0, 60.0, f64::NAN]).unwrap();
+        assert_eq!(
+            shape,
+            BoxShape {
+                x: 5.0,
+                min: 50.0,
+                q1: 40.0,
+                median: 30.0,
+                q3: 20.0,
+                max: 10.0,
+                outliers: vec![60.0],
+            }
+        );
+        assert_eq!(
+            shape.whiskers_path(4.0),
+            "M 5 40 L 5 50 M 3 50 L 7 50 M 5 20 L 5 10 M 3 10 L 7 10"
+        );
+        // Missing
+        assert_eq!(
+            BoxShape::new(5.0, f64::NAN, &[50.0, 40.0, 20.0, 10.0]),
+            None
+        );
+        assert_eq!(
+            BoxShape::new(5.0, 30.0, &[50.0, 40.0, f64::NAN, 10.0]),
+            None
+        );
+        assert_eq!(BoxShape::new(5.0, 30.0, &[50.0, 40.0]), None);
+    }
+
+    #[test]
+    fn test_format_values() {
+        let extra = ["1", "2", "4", "5", "9"].map(String::from);
+        assert_eq!(
+            UseBoxPlot::format_values("3".to_string(), &extra),
+            "1 | 2 [3] 4 | 5"
+        );
+        assert_eq!(UseBoxPlot::format_values("3".to_string(), &[]), "3");
+    }
+}
@@ -0,0 +1,319 @@
+use super::{
+    bar::use_group_slot, ApplyUseSeries, GetYValue, IntoUseCandlestick, SeriesAcc, UseY, YAxis,
+};
+use crate::{state::State, BarOrientation, Colour, Tick, BAR_GAP, BAR_GAP_INNER};
+use leptos::*;
+use std::rc::Rc;
+
+/// Default colour of a rising candle (close at or above open).
+pub const CANDLESTICK_RISING_COLOUR: Colour = Colour::from_rgb(0x26, 0xA6, 0x9A);
+/// Default colour of a falling candle (close below open).
+pub const CANDLESTICK_FALLING_COLOUR: Colour = Colour::from_rgb(0xEF, 0x53, 0x50);
+
+/// Draws a candlestick for each value on the chart: a body from the open to the close with a wick from the low to the high. Commonly used for OHLC (open, high, low, close) financial data.
+///
+/// # Example
+/// ```rust
+/// # use leptos_chartistry::*;
+/// # struct Price { day: f64, open: f64, high: f64, low: f64, close: f64 }
+/// let series = Series::new(|p: &Price| p.day).candlestick(
+///     Candlestick::new(
+///         |p: &Price| p.open,
+///         |p: &Price| p.high,
+///         |p: &Price| p.low,
+///         |p: &Price| p.close,
+///     )
+///     .with_name("ACME"),
+/// );
+/// ```
+/// See this in action with the [candlestick chart example](https://feral-dot-io.github.io/leptos-chartistry/examples.html#candlestick-chart).
+///
+/// Candles are sized like a [Bar] and share each X value with any bars and box plots. The close is used as the Y value e.g., for the nearest series and [DataPoint](crate::DataPoint). The tooltip shows all four values.
+///
+/// [Bar]: crate::Bar
+#[non_exhaustive]
+pub struct Candlestick<T, Y> {
+    get_y: Rc<GetOhlc<T, Y>>,
+    /// Name of the candlestick. Used in the legend and tooltip.
+    pub name: RwSignal<String>,
+    /// Colour of rising candles (close at or above open). Default is [CANDLESTICK_RISING_COLOUR].
+    pub rising_colour: RwSignal<Colour>,
+    /// Colour of falling candles (close below open). Default is [CANDLESTICK_FALLING_COLOUR].
+    pub falling_colour: RwSignal<Colour>,
+    /// Gap between candles. Clamped to 0.0 and 1.0. Default is [BAR_GAP]. See [Bar::gap](crate::Bar::gap) for details.
+    pub gap: RwSignal<f64>,
+    /// Gap between candles, bars and box plots sharing an X value. Clamped to 0.0 and 1.0. Default is [BAR_GAP_INNER]. See [Bar::group_gap](crate::Bar::group_gap) for details.
+    pub group_gap: RwSignal<f64>,
+    /// Width of the wick. Default is 1.0.
+    pub width: RwSignal<f64>,
+    /// Which Y axis the candlestick is projected against. Default is [YAxis::Primary].
+    pub y_axis: RwSignal<YAxis>,
+}
+
+struct GetOhlc<T, Y> {
+    open: Rc<dyn Fn(&T) -> Y>,
+    high: Rc<dyn Fn(&T) -> Y>,
+    low: Rc<dyn Fn(&T) -> Y>,
+    close: Rc<dyn Fn(&T) -> Y>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UseCandlestick {
+    group_id: usize,
+    rising_colour: RwSignal<Colour>,
+    falling_colour: RwSignal<Colour>,
+    gap: RwSignal<f64>,
+    group_gap: RwSignal<f64>,
+    width: RwSignal<f64>,
+}
+
+impl<T, Y> Candlestick<T, Y> {
+    /// Create a new candlestick. Each getter extracts a Y value (open, high, low and close) from your struct.
+    ///
+    /// See the module documentation for examples.
+    pub fn new(
+        open: impl Fn(&T) -> Y + 'static,
+        high: impl Fn(&T) -> Y + 'static,
+        low: impl Fn(&T) -> Y + 'static,
+        close: impl Fn(&T) -> Y + 'static,
+    ) -> Self
+    where
+        Y: Tick,
+    {
+        Self {
+            get_y: Rc::new(GetOhlc {
+                open: Rc::new(open),
+                high: Rc::new(high),
+                low: Rc::new(low),
+                close: Rc::new(close),
+            }),
+            name: RwSignal::default(),
+            rising_colour: create_rw_signal(CANDLESTICK_RISING_COLOUR),
+            falling_colour: create_rw_signal(CANDLESTICK_FALLING_COLOUR),
+            gap: create_rw_signal(BAR_GAP),
+            group_gap: create_rw_signal(BAR_GAP_INNER),
+            width: create_rw_signal(1.0),
+            y_axis: RwSignal::default(),
+        }
+    }
+
+    /// Set the name of the candlestick. Used in the legend and tooltip.
+    pub fn with_name(self, name: impl Into<String>) -> Self {
+        self.name.set(name.into());
+        self
+    }
+
+    /// Set the colour of rising candles (close at or above open).
+    pub fn with_rising_colour(self, colour: impl Into<Colour>) -> Self {
+        self.rising_colour.set(colour.into());
+        self
+    }
+
+    /// Set the colour of falling candles (close below open).
+    pub fn with_falling_colour(self, colour: impl Into<Colour>) -> Self {
+        self.falling_colour.set(colour.into());
+        self
+    }
+
+    /// Set the gap between candles. Clamped to 0.0 and 1.0.
+    pub fn with_gap(self, gap: impl Into<f64>) -> Self {
+        self.gap.set(gap.into());
+        self
+    }
+
+    /// Set the gap between candles, bars and box plots sharing an X value. Clamped to 0.0 and 1.0.
+    pub fn with_group_gap(self, group_gap: impl Into<f64>) -> Self {
+        self.group_gap.set(group_gap.into());
+        self
+    }
+
+    /// Set the width of the wick.
+    pub fn with_width(self, width: impl Into<f64>) -> Self {
+        self.width.set(width.into());
+        self
+    }
+
+    /// Set which Y axis the candlestick is projected against.
+    pub fn with_y_axis(self, y_axis: impl Into<YAxis>) -> Self {
+        self.y_axis.set(y_axis.into());
+        self
+    }
+}
+
+impl<T, Y> Clone for Candlestick<T, Y> {
+    fn clone(&self) -> Self {
+        Self {
+            get_y: self.get_y.clone(),
+            name: self.name,
+            rising_colour: self.rising_colour,
+            falling_colour: self.falling_colour,
+            gap: self.gap,
+            group_gap: self.group_gap,
+            width: self.width,
+            y_axis: self.y_axis,
+        }
+    }
+}
+
+impl<T, Y> GetYValue<T, Y> for GetOhlc<T, Y> {
+    fn value(&self, t: &T) -> Y {
+        (self.close)(t)
+    }
+
+    fn cumulative_value(&self, t: &T) -> Y {
+        (self.close)(t)
+    }
+
+    fn extra_values(&self, t: &T) -> Vec<Y> {
+        vec![(self.open)(t), (self.high)(t), (self.low)(t)]
+    }
+}
+
+impl<T: 'static, Y: 'static> ApplyUseSeries<T, Y> for Candlestick<T, Y> {
+    fn apply_use_series(self: Rc<Self>, series: &mut SeriesAcc<T, Y>) {
+        // Rising and falling colours are used instead of the series' colours
+        _ = series.push_candlestick((*self).clone());
+    }
+}
+
+impl<T: 'static, Y: 'static> IntoUseCandlestick<T, Y> for Candlestick<T, Y> {
+    fn into_use_candlestick(self, id: usize, group_id: usize) -> (UseY, Rc<dyn GetYValue<T, Y>>) {
+        let candlestick = UseY::new_candlestick(
+            id,
+            self.name,
+            self.y_axis,
+            UseCandlestick {
+                group_id,
+                rising_colour: self.rising_colour,
+                falling_colour: self.falling_colour,
+                gap: self.gap,
+                group_gap: self.group_gap,
+                width: self.width,
+            },
+        );
+        (candlestick, self.get_y.clone())
+    }
+}
+
+impl UseCandlestick {
+    pub(super) fn group_id(&self) -> usize {
+        self.group_id
+    }
+
+    /// Offset and width of each candle's body within its X value. Shared with bars.
+    pub(super) fn use_slot<X: 'static, Y: 'static>(&self, state: &State<X, Y>) -> Memo<(f64, f64)> {
+        use_group_slot(
+            state,
+            Signal::derive(|| BarOrientation::Vertical),
+            self.gap,
+            self.group_gap,
+            self.group_id,
+        )
+    }
+
+    /// Formats the tooltip value from the (formatted) close and extra values.
+    pub(super) fn format_values(close: String, extra: &[String]) -> String {
+        match extra {
+            [open, high, low] => format!("O {open} H {high} L {low} C {close}"),
+            _ => close,
+        }
+    }
+}
+
+/// Returns the SVG (top, height) of a candle's body. None if missing (NaN).
+fn candle_body(open_y: f64, close_y: f64) -> Option<(f64, f64)> {
+    if open_y.is_nan() || close_y.is_nan() {
+        return None;
+    }
+    Some((open_y.min(close_y), (open_y - close_y).abs()))
+}
+
+#[component]
+pub fn RenderCandlestick(
+    candlestick: UseCandlestick,
+    /// Offset and width of each candle's body within its X value.
+    group_slot: Signal<(f64, f64)>,
+    positions: Signal<Vec<(f64, f64)>>,
+    extra: Signal<Vec<Vec<f64>>>,
+) -> impl IntoView {
+    let UseCandlestick {
+        rising_colour,
+        falling_colour,
+        width: wick_width,
+        ..
+    } = candlestick;
+
+    let candles = move || {
+        let (offset, width) = group_slot.get();
+        // Flat candles (open equals close) are drawn as a line
+        let min_height = wick_width.get();
+        let (rising, falling) = (rising_colour.get(), falling_colour.get());
+        positions.with(|positions| {
+            extra.with(|extra| {
+                positions
+                    .iter()
+                    .zip(extra)
+                    .filter_map(|(&(x, close_y), extra)| {
+                        let &[open_y, high_y, low_y] = extra.as_slice() else {
+                            return None;
+                        };
+                        let (top, height) = candle_body(open_y, close_y)?;
+                        let x = x + offset + width / 2.0;
+                        // SVG Y is inverted: a rising close is above the open
+                        let colour = if close_y <= open_y { rising } else { falling };
+                        let colour = colour.to_string();
+                        let wick = (!high_y.is_nan() && !low_y.is_nan()).then(|| {
+                            view! {
+                                <line x1=x y1=high_y x2=x y2=low_y stroke=colour.clone() />
+                            }
+                        });
+                        Some(view! {
+                            {wick}
+                            <rect
+                                x=x - width / 2.0
+                                y=top
+                                width=width
+                                height=height.max(min_height)
+                                fill=colour.clone()
+                                stroke=colour />
+                        })
+                    })
+                    .collect_view()
+            })
+        })
+    };
+
+    view! {
+        <g class="_chartistry_candlestick" stroke-width=wick_width>
+            {candles}
+        </g>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_candle_body() {
+        // Rising: close above open (SVG Y inverted)
+        assert_eq!(candle_body(50.0, 20.0), Some((20.0, 30.0)));
+        // Falling
+        assert_eq!(candle_body(20.0, 50.0), Some((20.0, 30.0)));
+        // Flat
+        assert_eq!(candle_body(20.0, 20.0), Some((20.0, 0.0)));
+        // Missing
+        assert_eq!(candle_body(f64::NAN, 20.0), None);
+        assert_eq!(candle_body(20.0, f64::NAN), None);
+    }
+
+    #[test]
+    fn test_format_values() {
+        let extra = ["1".to_string(), "4".to_string(), "0".to_string()];
+        assert_eq!(
+            UseCandlestick::format_values("3".to_string(), &extra),
+            "O 1 H 4 L 0 C 3"
+        );
+        assert_eq!(UseCandlestick::format_values("3".to_string(), &[]), "3");
+    }
+}
@@ -0,0 +1,253 @@
+use super::GetYValue;
+use crate::Colour;
+use leptos::*;
+use std::rc::Rc;
+
+/// Default opacity of an error band.
+pub const ERROR_BAND_OPACITY: f64 = 0.3;
+/// Default width of the caps at each end of a whisker.
+pub const ERROR_CAP_WIDTH: f64 = 8.0;
+
+/// Describes how the lower and upper values of a [Line](crate::Line) or [Bar](crate::Bar) are drawn e.g., error bars or a confidence band. The values are set with [Line::with_error](crate::Line::with_error) and [Bar::with_error](crate::Bar::with_error).
+#[derive(Clone, Debug, PartialEq)]
+pub struct ErrorBars {
+    /// How the lower and upper values are drawn. Default is [ErrorStyle::Whiskers].
+    pub style: RwSignal<ErrorStyle>,
+    /// Colour of the whiskers or band. Default is the series colour.
+    pub colour: RwSignal<Option<Colour>>,
+    /// Width of the whisker lines. Default is 1.0.
+    pub width: RwSignal<f64>,
+    /// Width of the caps at each end of a whisker. Default is [ERROR_CAP_WIDTH].
+    pub cap_width: RwSignal<f64>,
+    /// Opacity of a band. Clamped to 0.0 and 1.0. Default is [ERROR_BAND_OPACITY].
+    pub opacity: RwSignal<f64>,
+}
+
+/// How the lower and upper values of a series are drawn.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub enum ErrorStyle {
+    /// Draws a vertical whisker with caps from the lower to the upper value at each point.
+    #[default]
+    Whiskers,
+    /// Shades a translucent band between the lower and upper values. Uses the line's interpolation. Bars always use whiskers.
+    Band,
+}
+
+/// Wraps a Y value getter with lower and upper values.
+pub(super) struct GetError<T, Y> {
+    pub get_y: Rc<dyn GetYValue<T, Y>>,
+    pub lower: Rc<dyn Fn(&T) -> Y>,
+    pub upper: Rc<dyn Fn(&T) -> Y>,
+}
+
+impl Default for ErrorBars {
+    fn default() -> Self {
+        Self {
+            style: RwSignal::default(),
+            colour: RwSignal::default(),
+            width: create_rw_signal(1.0),
+            cap_width: create_rw_signal(ERROR_CAP_WIDTH),
+            opacity: create_rw_signal(ERROR_BAND_OPACITY),
+        }
+    }
+}
+
+impl From<ErrorStyle> for ErrorBars {
+    fn from(style: ErrorStyle) -> Self {
+        Self::from_style(style)
+    }
+}
+
+impl ErrorBars {
+    /// Create new error bars drawn with the given style.
+    pub fn from_style(style: impl Into<ErrorStyle>) -> Self {
+        Self {
+            style: create_rw_signal(style.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Create new error bars drawn as a translucent band.
+    pub fn band() -> Self {
+        Self::from_style(ErrorStyle::Band)
+    }
+
+    /// Set the colour of the whiskers or band. Default is the series colour.
+    pub fn with_colour(self, colour: impl Into<Option<Colour>>) -> Self {
+        self.colour.set(colour.into());
+        self
+    }
+
+    /// Set the width of the whisker lines.
+    pub fn with_width(self, width: impl Into<f64>) -> Self {
+        self.width.set(width.into());
+        self
+    }
+
+    /// Set the width of the caps at each end of a whisker.
+    pub fn with_cap_width(self, cap_width: impl Into<f64>) -> Self {
+        self.cap_width.set(cap_width.into());
+        self
+    }
+
+    /// Set the opacity of a band. Clamped to 0.0 and 1.0.
+    pub fn with_opacity(self, opacity: impl Into<f64>) -> Self {
+        self.opacity.set(opacity.into());
+        self
+    }
+}
+
+impl<T, Y> GetYValue<T, Y> for GetError<T, Y> {
+    fn value(&self, t: &T) -> Y {
+        self.get_y.value(t)
+    }
+
+    fn cumulative_value(&self, t: &T) -> Y {
+        self.get_y.cumulative_value(t)
+    }
+
+    fn extra_values(&self, t: &T) -> Vec<Y> {
+        vec![(self.lower)(t), (self.upper)(t)]
+    }
+}
+
+impl std::str::FromStr for ErrorStyle {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "whiskers" => Ok(ErrorStyle::Whiskers),
+            "band" => Ok(ErrorStyle::Band),
+            _ => Err(format!("unknown error style: `{}`", s)),
+        }
+    }
+}
+
+impl std::fmt::Display for ErrorStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ErrorStyle::Whiskers => write!(f, "whiskers"),
+            ErrorStyle::Band => write!(f, "band"),
+        }
+    }
+}
+
+/// Formats the tooltip value from the (formatted) Y value and its lower and upper values.
+pub(super) fn format_values(value: String, extra: &[String]) -> String {
+    match extra {
+        [lower, upper] => format!("{value} ({lower} – {upper})"),
+        _ => value,
+    }
+}
+
+/// SVG points of the lower and upper values.
+type BandPoints = (Vec<(f64, f64)>, Vec<(f64, f64)>);
+
+/// Returns the SVG (lower, upper) points of each position. Missing values are NaN.
+pub(super) fn band_points(positions: &[(f64, f64)], extra: &[Vec<f64>]) -> BandPoints {
+    positions
+        .iter()
+        .zip(extra)
+        .map(|(&(x, _), extra)| match extra.as_slice() {
+            &[lower, upper] => ((x, lower), (x, upper)),
+            _ => ((x, f64::NAN), (x, f64::NAN)),
+        })
+        .unzip()
+}
+
+/// Returns an SVG path of a capped whisker from the lower to the upper value of each position. Skips missing values.
+fn whiskers_path(positions: &[(f64, f64)], extra: &[Vec<f64>], cap_width: f64) -> String {
+    let half = cap_width / 2.0;
+    let (lower, upper) = band_points(positions, extra);
+    lower
+        .into_iter()
+        .zip(upper)
+        .filter(|&((x, lower), (_, upper))| !(x.is_nan() || lower.is_nan() || upper.is_nan()))
+        .map(|((x, lower), (_, upper))| {
+            format!(
+                "M {x} {lower} L {x} {upper} M {} {lower} L {} {lower} M {} {upper} L {} {upper} ",
+                x - half,
+                x + half,
+                x - half,
+                x + half
+            )
+        })
+        .collect()
+}
+
+#[component]
+pub fn RenderWhiskers(
+    error_bars: ErrorBars,
+    /// Fallback colour if the error bars don't have one.
+    colour: Signal<String>,
+    positions: Signal<Vec<(f64, f64)>>,
+    extra: Signal<Vec<Vec<f64>>>,
+) -> impl IntoView {
+    let ErrorBars {
+        colour: error_colour,
+        width,
+        cap_width,
+        ..
+    } = error_bars;
+    let stroke = move || {
+        error_colour
+            .get()
+            .map_or_else(|| colour.get(), |c| c.to_string())
+    };
+    let path = move || {
+        let cap_width = cap_width.get();
+        positions.with(|positions| extra.with(|extra| whiskers_path(positions, extra, cap_width)))
+    };
+    view! {
+        <path
+            class="_chartistry_error_whiskers"
+            d=path
+            fill="none"
+            stroke=stroke
+            stroke-width=width />
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_band_points() {
+        let positions = [(1.0, 5.0), (2.0, 6.0), (3.0, 7.0)];
+        let extra = [vec![6.0, 4.0], vec![], vec![8.0, f64::NAN]];
+        let (lower, upper) = band_points(&positions, &extra);
+        assert_eq!(lower[0], (1.0, 6.0));
+        assert_eq!(upper[0], (1.0, 4.0));
+        assert!(lower[1].1.is_nan() && upper[1].1.is_nan());
+        assert_eq!(lower[2], (3.0, 8.0));
+        assert!(upper[2].1.is_nan());
+    }
+
+    #[test]
+    fn test_whiskers_path() {
+        let positions = [(1.0, 5.0), (2.0, 6.0)];
+        let extra = [vec![6.0, 4.0], vec![f64::NAN, 5.0]];
+        assert_eq!(
+            whiskers_path(&positions, &extra, 2.0),
+            "M 1 6 L 1 4 M 0 6 L 2 6 M 0 4 L 2 4 "
+        );
+        assert_eq!(whiskers_path(&[], &[], 2.0), "");
+    }
+
+    #[test]
+    fn test_format_values() {
+        let extra = ["1".to_string(), "4".to_string()];
+        assert_eq!(format_values("3".to_string(), &extra), "3 (1 – 4)");
+        assert_eq!(format_values("3".to_string(), &[]), "3");
+    }
+
+    #[test]
+    fn test_error_style_from_str() {
+        for style in [ErrorStyle::Whiskers, ErrorStyle::Band] {
+            assert_eq!(style.to_string().parse::<ErrorStyle>(), Ok(style));
+        }
+        assert!("nope".parse::<ErrorStyle>().is_err());
+    }
+}
@@ -0,0 +1,102 @@
+use crate::colours::Colour;
+use leptos::*;
+
+/// Default opacity of a line's area fill.
+pub const FILL_OPACITY: f64 = 0.5;
+
+/// Describes an area fill between a line and a baseline.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Fill {
+    /// Where the fill extends to. Default is `None` (no fill).
+    pub baseline: RwSignal<Option<FillBaseline>>,
+    /// Colour of the fill. Default is the line colour (or gradient).
+    pub colour: RwSignal<Option<Colour>>,
+    /// Opacity of the fill. Clamped to 0.0 and 1.0. Default is 0.5.
+    pub opacity: RwSignal<f64>,
+}
+
+/// Where an area fill extends to from its line.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub enum FillBaseline {
+    /// Fills to the zero line.
+    #[default]
+    Zero,
+    /// Fills to the bottom edge of the chart.
+    Edge,
+    /// Fills to another line in the same series. Every series (lines, bars, scatters, etc.) is given an ID in the order it's added to the [Series](crate::Series), starting from zero. Each layer of a stack takes its own ID. Uses this line's interpolation for both edges. Falls back to [FillBaseline::Zero] if the line doesn't exist or is hidden.
+    Line(usize),
+}
+
+/// A fill ready to render in SVG coordinates.
+#[derive(Clone, Debug, PartialEq)]
+pub enum UseFill {
+    /// SVG Y to fill to.
+    Base(f64),
+    /// SVG positions of another line to fill to.
+    Line(Vec<(f64, f64)>),
+}
+
+impl Default for Fill {
+    fn default() -> Self {
+        Self {
+            baseline: RwSignal::default(),
+            colour: RwSignal::default(),
+            opacity: create_rw_signal(FILL_OPACITY),
+        }
+    }
+}
+
+impl From<FillBaseline> for Fill {
+    fn from(baseline: FillBaseline) -> Self {
+        Self::from_baseline(baseline)
+    }
+}
+
+impl Fill {
+    /// Create a new fill extending to the given baseline.
+    pub fn from_baseline(baseline: impl Into<FillBaseline>) -> Self {
+        Self {
+            baseline: create_rw_signal(Some(baseline.into())),
+            ..Default::default()
+        }
+    }
+
+    /// Set the colour of the fill. Default is the line colour.
+    pub fn with_colour(self, colour: impl Into<Option<Colour>>) -> Self {
+        self.colour.set(colour.into());
+        self
+    }
+
+    /// Set the opacity of the fill. Clamped to 0.0 and 1.0.
+    pub fn with_opacity(self, opacity: impl Into<f64>) -> Self {
+        self.opacity.set(opacity.into());
+        self
+    }
+}
+
+impl std::str::FromStr for FillBaseline {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "zero" => Ok(FillBaseline::Zero),
+            "edge" => Ok(FillBaseline::Edge),
+            _ => s
+                .strip_prefix("line ")
+                .and_then(|id| id.parse().ok())
+                .map(FillBaseline::Line)
+                .ok_or_else(|| format!("unknown fill baseline: `{}`", s)),
+        }
+    }
+}
+
+impl std::fmt::Display for FillBaseline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FillBaseline::Zero => write!(f, "zero"),
+            FillBaseline::Edge => write!(f, "edge"),
+            FillBaseline::Line(id) => write!(f, "line {}", id),
+        }
+    }
+}
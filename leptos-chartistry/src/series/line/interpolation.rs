@@ -71,6 +71,52 @@ impl Interpolation {
             Self::Monotone => monotone(points),
         }
     }
+
+    /// Path of the area between the line and a horizontal line at `base_y`. Each segment (separated by NaN) is closed separately.
+    pub(super) fn area_path(self, points: &[(f64, f64)], base_y: f64) -> String {
+        points
+            .split(|(x, y)| x.is_nan() || y.is_nan())
+            .filter(|segment| !segment.is_empty())
+            .map(|segment| {
+                let (first_x, _) = segment[0];
+                let (last_x, _) = segment[segment.len() - 1];
+                format!(
+                    "{}L {} {} L {} {} Z ",
+                    self.path(segment),
+                    last_x,
+                    base_y,
+                    first_x,
+                    base_y
+                )
+            })
+            .collect::<String>()
+    }
+
+    /// Path of the area between two lines with the same X positions: along `points` then back along `other`. Each segment where both lines have a value is closed separately.
+    pub(super) fn band_path(self, points: &[(f64, f64)], other: &[(f64, f64)]) -> String {
+        let is_nan = |&(x, y): &(f64, f64)| x.is_nan() || y.is_nan();
+        let pairs = points.iter().zip(other).collect::<Vec<_>>();
+        pairs
+            .split(|(a, b)| is_nan(a) || is_nan(b))
+            .filter(|segment| !segment.is_empty())
+            .map(|segment| {
+                let forward = segment.iter().map(|(&a, _)| a).collect::<Vec<_>>();
+                let back = segment.iter().rev().map(|(_, &b)| b).collect::<Vec<_>>();
+                // Join the lines: the return path continues from the end of the first
+                let back = self.reversed().path(&back).replacen('M', "L", 1);
+                format!("{}{}Z ", self.path(&forward), back)
+            })
+            .collect::<String>()
+    }
+
+    /// Interpolation that draws the same line when given the points in reverse.
+    fn reversed(self) -> Self {
+        match self {
+            Self::Step(Step::Horizontal) => Self::Step(Step::Vertical),
+            Self::Step(Step::Vertical) => Self::Step(Step::Horizontal),
+            _ => self,
+        }
+    }
 }
 
 fn linear(points: &[(f64, f64)]) -> String {
@@ -181,3 +227,36 @@ fn tangent(x_prev: f64, x: f64, x_next: f64, y_prev: f64, y: f64, y_next: f64) -
     // Tangent
     (slope_prev.signum() + slope.signum()) * slope_prev.abs().min(0.5 * para.abs())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_area_path() {
+        let points = [(1.0, 2.0), (3.0, 4.0), (f64::NAN, 5.0), (6.0, 7.0)];
+        assert_eq!(
+            Interpolation::Linear.area_path(&points, 10.0),
+            "M 1 2 L 3 4 L 3 10 L 1 10 Z M 6 7 L 6 10 L 6 10 Z "
+        );
+        assert_eq!(Interpolation::Linear.area_path(&[], 10.0), "");
+    }
+
+    #[test]
+    fn test_band_path() {
+        // Lines cross: the band covers both sides
+        let points = [(1.0, 2.0), (3.0, 8.0), (f64::NAN, 5.0), (6.0, 7.0)];
+        let other = [(1.0, 6.0), (3.0, 4.0), (5.0, 5.0), (6.0, f64::NAN)];
+        assert_eq!(
+            Interpolation::Linear.band_path(&points, &other),
+            "M 1 2 L 3 8 L 3 4 L 1 6 Z "
+        );
+        // Steps are drawn back along the same line
+        let step = Interpolation::Step(Step::Horizontal);
+        assert_eq!(
+            step.band_path(&points[..2], &other[..2]),
+            "M 1 2 H 3 V 8 L 3 4 V 6 H 1 Z "
+        );
+        assert_eq!(Interpolation::Linear.band_path(&[], &[]), "");
+    }
+}
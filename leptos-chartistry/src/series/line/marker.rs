@@ -1,4 +1,3 @@
-use super::UseLine;
 use crate::colours::Colour;
 use leptos::*;
 
@@ -93,10 +92,14 @@ impl Marker {
     }
 }
 
+/// Renders a marker at each position. Sized relative to the given line width.
 #[component]
-pub(super) fn LineMarkers(line: UseLine, positions: Signal<Vec<(f64, f64)>>) -> impl IntoView {
-    let marker = line.marker.clone();
-
+pub(crate) fn RenderMarkers(
+    marker: Marker,
+    #[prop(into)] colour: Signal<Colour>,
+    #[prop(into)] line_width: Signal<f64>,
+    positions: Signal<Vec<(f64, f64)>>,
+) -> impl IntoView {
     // Disable border if no marker
     let border_width = Signal::derive(move || {
         if marker.shape.get() == MarkerShape::None {
@@ -113,7 +116,7 @@ pub(super) fn LineMarkers(line: UseLine, positions: Signal<Vec<(f64, f64)>>) ->
         }
 
         // Size of our marker: proportionate to our line width
-        let line_width = line.width.get();
+        let line_width = line_width.get();
         let diameter = line_width * WIDTH_TO_MARKER * marker.scale.get();
 
         positions.with(|positions| {
@@ -136,8 +139,8 @@ pub(super) fn LineMarkers(line: UseLine, positions: Signal<Vec<(f64, f64)>>) ->
 
     view! {
         <g
-            fill=move || marker.colour.get().unwrap_or_else(|| line.colour.get()).to_string()
-            stroke=move || marker.border.get().unwrap_or_else(|| line.colour.get()).to_string()
+            fill=move || marker.colour.get().unwrap_or_else(|| colour.get()).to_string()
+            stroke=move || marker.border.get().unwrap_or_else(|| colour.get()).to_string()
             stroke-width=move || border_width.get() * 2.0 // Half of the stroke is inside
             class="_chartistry_line_markers">
             {markers}
@@ -1,12 +1,21 @@
+mod fill;
 mod interpolation;
 mod marker;
+use fill::UseFill;
+pub use fill::{Fill, FillBaseline, FILL_OPACITY};
 pub use interpolation::{Interpolation, Step};
+pub(super) use marker::RenderMarkers;
 pub use marker::{Marker, MarkerShape};
 
-use super::{ApplyUseSeries, IntoUseLine, SeriesAcc, UseData, UseY};
+use super::{
+    error_bars::{band_points, GetError, RenderWhiskers},
+    ApplyUseSeries, ErrorBars, ErrorStyle, IntoUseLine, SeriesAcc, UseData, UseY, YAxis,
+};
 use crate::{
+    bounds::Bounds,
     colours::{Colour, DivergingGradient, LinearGradientSvg, SequentialGradient, BERLIN, LIPARI},
     series::GetYValue,
+    state::State,
     ColourScheme, Tick,
 };
 use leptos::*;
@@ -18,6 +27,9 @@ pub const LINEAR_GRADIENT: SequentialGradient = LIPARI;
 /// Suggested colour scheme for a diverging gradient on a line. Uses a blue for negative values, a dark central value and red for positive values. Assumes a light background.
 pub const DIVERGING_GRADIENT: DivergingGradient = BERLIN;
 
+/// Multiplies the width of a highlighted line e.g., nearest the mouse.
+const HIGHLIGHT_WIDTH: f64 = 2.0;
+
 /// Draws a line on the chart.
 ///
 /// # Simple example
@@ -41,6 +53,29 @@ pub const DIVERGING_GRADIENT: DivergingGradient = BERLIN;
 ///     .line(Line::new(|data: &MyData| data.y2).with_name("apples"));
 /// ```
 /// See this in action with the [legend example](https://feral-dot-io.github.io/leptos-chartistry/examples.html#legend).
+///
+/// # Area fill
+/// Lines can fill the area down to a [baseline](FillBaseline):
+/// ```rust
+/// # use leptos_chartistry::*;
+/// # struct MyData { x: f64, y1: f64 }
+/// let series = Series::new(|data: &MyData| data.x)
+///     .line(Line::new(|data: &MyData| data.y1).with_fill(FillBaseline::Zero));
+/// ```
+/// See this in action with the [area chart example](https://feral-dot-io.github.io/leptos-chartistry/examples.html#area-chart).
+///
+/// # Error bars
+/// Lines can show lower and upper values (e.g., a p5–p95 spread or a prediction interval) as whiskers or a shaded band:
+/// ```rust
+/// # use leptos_chartistry::*;
+/// # struct MyData { x: f64, p5: f64, p50: f64, p95: f64 }
+/// let series = Series::new(|data: &MyData| data.x).line(
+///     Line::new(|data: &MyData| data.p50)
+///         .with_error(|data: &MyData| data.p5, |data: &MyData| data.p95)
+///         .with_error_bars(ErrorStyle::Band),
+/// );
+/// ```
+/// See this in action with the [error bars example](https://feral-dot-io.github.io/leptos-chartistry/examples.html#error-bars).
 pub struct Line<T, Y> {
     get_y: Rc<dyn GetYValue<T, Y>>,
     /// Name of the line. Used in the legend.
@@ -55,6 +90,12 @@ pub struct Line<T, Y> {
     pub interpolation: RwSignal<Interpolation>,
     /// Marker at each point on the line.
     pub marker: Marker,
+    /// Area fill between the line and a baseline. Default is no fill.
+    pub fill: Fill,
+    /// How the lower and upper values set by [Line::with_error] are drawn. Default is whiskers.
+    pub error_bars: ErrorBars,
+    /// Which Y axis the line is projected against. Default is [YAxis::Primary].
+    pub y_axis: RwSignal<YAxis>,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -64,6 +105,9 @@ pub struct UseLine {
     width: RwSignal<f64>,
     interpolation: RwSignal<Interpolation>,
     marker: Marker,
+    fill: Fill,
+    fill_baseline: Signal<Option<FillBaseline>>,
+    error_bars: ErrorBars,
 }
 
 impl<T, Y> Line<T, Y> {
@@ -82,6 +126,9 @@ impl<T, Y> Line<T, Y> {
             width: 1.0.into(),
             interpolation: RwSignal::default(),
             marker: Marker::default(),
+            fill: Fill::default(),
+            error_bars: ErrorBars::default(),
+            y_axis: RwSignal::default(),
         }
     }
 
@@ -122,6 +169,69 @@ impl<T, Y> Line<T, Y> {
         self.marker = marker.into();
         self
     }
+
+    /// Set the area fill between the line and a baseline.
+    pub fn with_fill(mut self, fill: impl Into<Fill>) -> Self {
+        self.fill = fill.into();
+        self
+    }
+
+    /// Set lower and upper values drawn around the line e.g., a spread or confidence interval. Included in the Y range. Ignored when the line is stacked.
+    pub fn with_error(
+        mut self,
+        lower: impl Fn(&T) -> Y + 'static,
+        upper: impl Fn(&T) -> Y + 'static,
+    ) -> Self
+    where
+        T: 'static,
+        Y: 'static,
+    {
+        self.get_y = Rc::new(GetError {
+            get_y: self.get_y,
+            lower: Rc::new(lower),
+            upper: Rc::new(upper),
+        });
+        self
+    }
+
+    /// Set how the lower and upper values are drawn.
+    pub fn with_error_bars(mut self, error_bars: impl Into<ErrorBars>) -> Self {
+        self.error_bars = error_bars.into();
+        self
+    }
+
+    /// Set which Y axis the line is projected against.
+    pub fn with_y_axis(self, y_axis: impl Into<YAxis>) -> Self {
+        self.y_axis.set(y_axis.into());
+        self
+    }
+
+    /// Converts to a [UseLine] with a given fill baseline. Used to override the line's own baseline e.g., by a stack.
+    pub(super) fn into_use_line_with_baseline(
+        self,
+        id: usize,
+        colour: Memo<Colour>,
+        fill_baseline: Signal<Option<FillBaseline>>,
+    ) -> (UseY, Rc<dyn GetYValue<T, Y>>) {
+        let override_colour = self.colour;
+        let colour = Signal::derive(move || override_colour.get().unwrap_or(colour.get()));
+        let line = UseY::new_line(
+            id,
+            self.name,
+            self.y_axis,
+            UseLine {
+                colour,
+                gradient: self.gradient,
+                width: self.width,
+                interpolation: self.interpolation,
+                marker: self.marker.clone(),
+                fill: self.fill.clone(),
+                fill_baseline,
+                error_bars: self.error_bars.clone(),
+            },
+        );
+        (line, self.get_y.clone())
+    }
 }
 
 impl<T, Y> Clone for Line<T, Y> {
@@ -134,6 +244,9 @@ impl<T, Y> Clone for Line<T, Y> {
             width: self.width,
             interpolation: self.interpolation,
             marker: self.marker.clone(),
+            fill: self.fill.clone(),
+            error_bars: self.error_bars.clone(),
+            y_axis: self.y_axis,
         }
     }
 }
@@ -163,32 +276,76 @@ impl<T, Y> ApplyUseSeries<T, Y> for Line<T, Y> {
 
 impl<T, Y> IntoUseLine<T, Y> for Line<T, Y> {
     fn into_use_line(self, id: usize, colour: Memo<Colour>) -> (UseY, Rc<dyn GetYValue<T, Y>>) {
-        let override_colour = self.colour;
-        let colour = Signal::derive(move || override_colour.get().unwrap_or(colour.get()));
-        let line = UseY::new_line(
-            id,
-            self.name,
-            UseLine {
-                colour,
-                gradient: self.gradient,
-                width: self.width,
-                interpolation: self.interpolation,
-                marker: self.marker.clone(),
-            },
-        );
-        (line, self.get_y.clone())
+        let fill_baseline = self.fill.baseline.into();
+        self.into_use_line_with_baseline(id, colour, fill_baseline)
+    }
+}
+
+impl UseLine {
+    /// Resolves the fill to SVG coordinates for the chart.
+    pub(super) fn use_fill<X: Tick, Y: Tick>(
+        &self,
+        state: &State<X, Y>,
+    ) -> Signal<Option<UseFill>> {
+        let fill_baseline = self.fill_baseline;
+        let inner = state.layout.inner;
+        let svg_zero = state.svg_zero;
+        let data = state.pre.data.clone();
+        let projection = state.projection;
+        Signal::derive(move || {
+            let inner = inner.get();
+            let zero = UseFill::Base(svg_zero.get().1);
+            fill_baseline.get().map(|baseline| match baseline {
+                FillBaseline::Zero => zero,
+                FillBaseline::Edge => UseFill::Base(inner.bottom_y()),
+                FillBaseline::Line(id) => {
+                    let exists = data
+                        .visible
+                        .with(|series| series.iter().any(|s| s.id == id));
+                    if !exists {
+                        return zero;
+                    }
+                    UseFill::Line(data.svg_positions(id, &projection.get()))
+                }
+            })
+        })
+    }
+
+    /// Resolves the fill to the bottom of the given bounds. Used for the legend.
+    pub(super) fn use_fill_bounds(&self, bounds: Memo<Bounds>) -> Signal<Option<UseFill>> {
+        let fill_baseline = self.fill_baseline;
+        Signal::derive(move || {
+            fill_baseline
+                .get()
+                .map(|_| UseFill::Base(bounds.get().bottom_y()))
+        })
     }
 }
 
 #[component]
-pub fn RenderLine<X: 'static, Y: 'static>(
+pub fn RenderLine<X: 'static, Y: Clone + 'static>(
     use_y: UseY,
     line: UseLine,
     data: UseData<X, Y>,
     positions: Signal<Vec<(f64, f64)>>,
     markers: Signal<Vec<(f64, f64)>>,
+    fill: Signal<Option<UseFill>>,
+    /// Emphasise the line e.g., nearest the mouse.
+    #[prop(into, optional)]
+    highlight: MaybeSignal<bool>,
+    /// SVG lower and upper positions of error bars. Empty if none.
+    #[prop(optional)]
+    error: Signal<Vec<Vec<f64>>>,
 ) -> impl IntoView {
     let path = move || positions.with(|positions| line.interpolation.get().path(positions));
+    let width = line.width;
+    let stroke_width = move || {
+        if highlight.get() {
+            width.get() * HIGHLIGHT_WIDTH
+        } else {
+            width.get()
+        }
+    };
 
     // Line colour
     let gradient_id = format!("line_{}_gradient", use_y.id);
@@ -209,7 +366,78 @@ pub fn RenderLine<X: 'static, Y: 'static>(
             .get()
             .unwrap_or_else(|| LINEAR_GRADIENT.into())
     });
-    let range_y = Signal::derive(move || data.range_y.with(|range_y| range_y.positions()));
+    let range_y = {
+        let range_y = data.range_y_for(use_y.y_axis.into());
+        Signal::derive(move || range_y.with(|range_y| range_y.positions()))
+    };
+
+    // Area fill
+    let fill_path = move || {
+        let interpolation = line.interpolation.get();
+        fill.with(|fill| {
+            positions.with(|positions| match fill.as_ref()? {
+                UseFill::Base(base_y) => Some(interpolation.area_path(positions, *base_y)),
+                UseFill::Line(other) => Some(interpolation.band_path(positions, other)),
+            })
+        })
+    };
+    let fill_colour = {
+        let fill_colour = line.fill.colour;
+        move || {
+            fill_colour
+                .get()
+                .map_or_else(|| stroke.get(), |c| c.to_string())
+        }
+    };
+    let fill_opacity = line.fill.opacity;
+
+    // Error bars: whiskers or a band between the upper and lower lines
+    let error_bars = line.error_bars.clone();
+    let band = create_memo(move |_| {
+        if error_bars.style.get() != ErrorStyle::Band {
+            return None;
+        }
+        positions.with(|positions| {
+            error.with(|error| {
+                let (lower, upper) = band_points(positions, error);
+                Some(line.interpolation.get().band_path(&upper, &lower))
+            })
+        })
+    });
+    let band_view = {
+        let error_bars = line.error_bars.clone();
+        move || {
+            band.get().map(|fill| {
+                let band_colour = error_bars
+                    .colour
+                    .get()
+                    .map_or_else(|| stroke.get(), |c| c.to_string());
+                view! {
+                    <path
+                        class="_chartistry_line_error_band"
+                        d=fill
+                        fill=band_colour
+                        fill-opacity=error_bars.opacity.get().clamp(0.0, 1.0)
+                        stroke="none" />
+                }
+            })
+        }
+    };
+    let whiskers = {
+        let error_bars = line.error_bars.clone();
+        let colour = Signal::derive(move || line.colour.get().to_string());
+        move || {
+            (error_bars.style.get() == ErrorStyle::Whiskers).then(|| {
+                view! {
+                    <RenderWhiskers
+                        error_bars=error_bars.clone()
+                        colour=colour
+                        positions=positions
+                        extra=error />
+                }
+            })
+        }
+    };
 
     view! {
         <g
@@ -217,7 +445,7 @@ pub fn RenderLine<X: 'static, Y: 'static>(
             stroke=stroke
             stroke-linecap="round"
             stroke-linejoin="bevel"
-            stroke-width=line.width>
+            stroke-width=stroke_width>
             <defs>
                 <Show when=move || line.gradient.get().is_some()>
                     <LinearGradientSvg
@@ -226,8 +454,20 @@ pub fn RenderLine<X: 'static, Y: 'static>(
                         range_y=range_y />
                 </Show>
             </defs>
+            <path
+                class="_chartistry_line_fill"
+                d=fill_path
+                fill=fill_colour
+                fill-opacity=move || fill_opacity.get().clamp(0.0, 1.0)
+                stroke="none" />
+            {band_view}
+            {whiskers}
             <path d=path fill="none" />
-            <marker::LineMarkers line=line positions=markers />
+            <RenderMarkers
+                marker=line.marker.clone()
+                colour=line.colour
+                line_width=line.width
+                positions=markers />
         </g>
     }
 }
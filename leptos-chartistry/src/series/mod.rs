@@ -1,20 +1,32 @@
 mod bar;
+mod box_plot;
+mod candlestick;
+mod error_bars;
 mod line;
+mod scatter;
 mod stack;
 mod use_data;
 mod use_y;
 
-pub use bar::{Bar, BarPlacement, BAR_GAP, BAR_GAP_INNER};
+pub use bar::{Bar, BarOrientation, BarPlacement, BAR_GAP, BAR_GAP_INNER};
+pub use box_plot::BoxPlot;
+pub use candlestick::{Candlestick, CANDLESTICK_FALLING_COLOUR, CANDLESTICK_RISING_COLOUR};
+pub use error_bars::{ErrorBars, ErrorStyle, ERROR_BAND_OPACITY, ERROR_CAP_WIDTH};
 pub use line::{
-    Interpolation, Line, Marker, MarkerShape, Step, DIVERGING_GRADIENT, LINEAR_GRADIENT,
+    Fill, FillBaseline, Interpolation, Line, Marker, MarkerShape, Step, DIVERGING_GRADIENT,
+    FILL_OPACITY, LINEAR_GRADIENT,
 };
+pub use scatter::Scatter;
 pub use stack::{Stack, STACK_COLOUR_SCHEME};
-pub use use_data::{RenderData, UseData};
+pub use use_data::{DataPoint, Range, RenderData, UseData};
 pub use use_y::{Snippet, UseY};
 
-use crate::colours::{Colour, ColourScheme};
+use crate::{
+    colours::{Colour, ColourScheme},
+    Scale,
+};
 use leptos::signal_prelude::*;
-use std::rc::Rc;
+use std::{collections::HashSet, rc::Rc};
 
 /// Arbitrary colours for a brighter palette than BATLOW
 pub const SERIES_COLOUR_SCHEME: [Colour; 10] = [
@@ -36,6 +48,22 @@ type GetY<T, Y> = Rc<dyn GetYValue<T, Y>>;
 trait GetYValue<T, Y> {
     fn value(&self, t: &T) -> Y;
     fn cumulative_value(&self, t: &T) -> Y;
+
+    /// Other Y values drawn alongside the value e.g., a candlestick's open, high and low or error bars. Extends the Y range.
+    fn extra_values(&self, _t: &T) -> Vec<Y> {
+        Vec::new()
+    }
+}
+
+/// Which Y axis a line, bar or scatter is projected against. See [Series] for details.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub enum YAxis {
+    /// The main Y axis. Uses [Series::min_y] and [Series::max_y].
+    #[default]
+    Primary,
+    /// A secondary Y axis with its own range. Uses [Series::min_y2] and [Series::max_y2]. Useful for overlaying data with unrelated units e.g., CPU % and request rate.
+    Secondary,
 }
 
 /// Describes how to render a series of data. A series is a collection of lines, bars, etc. that share the same X and Y axes.
@@ -81,6 +109,8 @@ trait GetYValue<T, Y> {
 ///
 /// This is how most series will be built and used. See a full example can be found in the [line chart example](https://feral-dot-io.github.io/leptos-chartistry/examples.html#line-chart).
 ///
+/// Bars, scatters, candlesticks and box plots are added in the same way with [Series::bar], [Series::scatter], [Series::candlestick] and [Series::box_plot].
+///
 /// ### Transforming data
 ///
 /// We can also apply transformations to the `Y` values. In our last example we used  octets to show bytes per second but if we wanted to show bits we could use `|r: &Rate| r.in_octets * 8` as the getter. We can also use signals here i.e., have a chart that can switch between bits and bytes.
@@ -103,9 +133,31 @@ trait GetYValue<T, Y> {
 ///
 /// This would render the lines on top of each other to show the total traffic. Check this out on the [stacked line chart example](https://feral-dot-io.github.io/leptos-chartistry/examples.html#stacked-line-chart).
 ///
+/// Stacks can also hold bars with [Stack::bar]. Each bar starts from the top of the previous layer and all bars in a stack share the same slot.
+///
+/// ### Secondary Y axis
+///
+/// Lines, bars and scatters can be assigned to a [secondary Y axis](YAxis::Secondary) with their `with_y_axis` method. These are projected against their own Y range while sharing the X axis. Pair with [TickLabels::with_y_axis](crate::TickLabels::with_y_axis) on the right edge to label it:
+///
+/// ```rust
+/// # use chrono::prelude::*;
+/// # use leptos_chartistry::*;
+/// # struct Load { at: DateTime<Utc>, cpu: f64, requests: f64 }
+/// let series = Series::new(|l: &Load| l.at)
+///     .line(Line::new(|l: &Load| l.cpu).with_name("CPU %"))
+///     .line(
+///         Line::new(|l: &Load| l.requests)
+///             .with_name("Requests")
+///             .with_y_axis(YAxis::Secondary),
+///     );
+/// let right = TickLabels::aligned_floats().with_y_axis(YAxis::Secondary);
+/// ```
+///
+/// Both axes share the [Y scale](Series::scale_y). Layers of a [Stack] should share the same axis.
+///
 /// ## Other options
 ///
-/// Finally, like most other components, you can control aspects such as the colour scheme and data ranges of X and Y.
+/// Finally, like most other components, you can control aspects such as the colour scheme, data ranges and scales (e.g., log) of X and Y.
 #[derive(Clone)]
 pub struct Series<T: 'static, X: 'static, Y: 'static> {
     get_x: GetX<T, X>,
@@ -118,8 +170,24 @@ pub struct Series<T: 'static, X: 'static, Y: 'static> {
     pub min_y: RwSignal<Option<Y>>,
     /// Optional maximum Y value. Extends the upper bound of the Y axis if set.
     pub max_y: RwSignal<Option<Y>>,
+    /// Optional minimum Y value of the [secondary axis](YAxis::Secondary). Extends the lower bound of the axis if set.
+    pub min_y2: RwSignal<Option<Y>>,
+    /// Optional maximum Y value of the [secondary axis](YAxis::Secondary). Extends the upper bound of the axis if set.
+    pub max_y2: RwSignal<Option<Y>>,
+    /// Optional zoomed X range. When set, the X axis shows only this range (clipping the data) and ignores [min_x](Self::min_x) and [max_x](Self::max_x). Written by [Zoom](crate::Zoom). Set to `None` to reset.
+    pub zoom_x: RwSignal<Option<(X, X)>>,
+    /// Optional zoomed Y range. When set, the Y axis shows only this range (clipping the data) and ignores [min_y](Self::min_y) and [max_y](Self::max_y). Written by [Zoom](crate::Zoom). Set to `None` to reset. The [secondary axis](YAxis::Secondary) is zoomed by the same proportion so series on both axes stay aligned.
+    pub zoom_y: RwSignal<Option<(Y, Y)>>,
+    /// Scale of the X axis. Default is [Scale::Linear].
+    pub scale_x: RwSignal<Scale>,
+    /// Scale of the Y axis. Default is [Scale::Linear].
+    pub scale_y: RwSignal<Scale>,
     /// Colour scheme for the series. If there are more lines than colours, the colours will repeat.
     pub colours: RwSignal<ColourScheme>,
+    /// IDs of hidden lines, bars, etc. Hidden series are not rendered and do not contribute to the Y range. Toggled by clicking a [Legend](struct@crate::Legend) entry. Default is empty.
+    ///
+    /// Every line, bar, scatter, etc. is given an ID in the order it's added to the series, starting from zero. Each layer of a [Stack] takes its own ID.
+    pub hidden: RwSignal<HashSet<usize>>,
 }
 
 trait ApplyUseSeries<T, Y> {
@@ -134,6 +202,23 @@ trait IntoUseBar<T, Y> {
     fn into_use_bar(self, id: usize, group_id: usize, colour: Memo<Colour>) -> (UseY, GetY<T, Y>);
 }
 
+trait IntoUseBoxPlot<T, Y> {
+    fn into_use_box_plot(
+        self,
+        id: usize,
+        group_id: usize,
+        colour: Memo<Colour>,
+    ) -> (UseY, GetY<T, Y>);
+}
+
+trait IntoUseCandlestick<T, Y> {
+    fn into_use_candlestick(self, id: usize, group_id: usize) -> (UseY, GetY<T, Y>);
+}
+
+trait IntoUseScatter<T, Y> {
+    fn into_use_scatter(self, id: usize, colour: Memo<Colour>) -> (UseY, GetY<T, Y>);
+}
+
 struct SeriesAcc<T, Y> {
     colour_id: usize,
     colours: RwSignal<ColourScheme>,
@@ -155,7 +240,14 @@ impl<T, X, Y> Series<T, X, Y> {
             max_x: RwSignal::default(),
             min_y: RwSignal::default(),
             max_y: RwSignal::default(),
+            min_y2: RwSignal::default(),
+            max_y2: RwSignal::default(),
+            zoom_x: RwSignal::default(),
+            zoom_y: RwSignal::default(),
+            scale_x: RwSignal::default(),
+            scale_y: RwSignal::default(),
             colours: create_rw_signal(SERIES_COLOUR_SCHEME.into()),
+            hidden: RwSignal::default(),
             series: Vec::new(),
         }
     }
@@ -200,6 +292,53 @@ impl<T, X, Y> Series<T, X, Y> {
         self.with_min_y(min_y).with_max_y(max_y)
     }
 
+    /// Set the minimum Y value of the [secondary axis](YAxis::Secondary). Extends the lower bound of the axis if set.
+    pub fn with_min_y2(self, min_y2: impl Into<Option<Y>>) -> Self {
+        self.min_y2.set(min_y2.into());
+        self
+    }
+
+    /// Set the maximum Y value of the [secondary axis](YAxis::Secondary). Extends the upper bound of the axis if set.
+    pub fn with_max_y2(self, max_y2: impl Into<Option<Y>>) -> Self {
+        self.max_y2.set(max_y2.into());
+        self
+    }
+
+    /// Set the Y range of the [secondary axis](YAxis::Secondary). Extends the lower and upper bounds of the axis if set.
+    pub fn with_y2_range(self, min_y2: impl Into<Option<Y>>, max_y2: impl Into<Option<Y>>) -> Self {
+        self.with_min_y2(min_y2).with_max_y2(max_y2)
+    }
+
+    /// Set the zoomed X range. Clips the X axis to this range. See [Zoom](crate::Zoom) for interactive zooming.
+    pub fn with_zoom_x(self, zoom_x: impl Into<Option<(X, X)>>) -> Self {
+        self.zoom_x.set(zoom_x.into());
+        self
+    }
+
+    /// Set the zoomed Y range. Clips the Y axis to this range. See [Zoom](crate::Zoom) for interactive zooming.
+    pub fn with_zoom_y(self, zoom_y: impl Into<Option<(Y, Y)>>) -> Self {
+        self.zoom_y.set(zoom_y.into());
+        self
+    }
+
+    /// Hides lines, bars, etc. by ID. See [hidden](Self::hidden) for how IDs are given. Layers of a [Stack] keep their place in the stack when others are hidden.
+    pub fn with_hidden(self, ids: impl IntoIterator<Item = usize>) -> Self {
+        self.hidden.set(ids.into_iter().collect());
+        self
+    }
+
+    /// Set the scale of the X axis e.g., [Scale::Log10]. Pair with [TickLabels::log_floats](crate::TickLabels::log_floats) for log ticks.
+    pub fn with_scale_x(self, scale: impl Into<Scale>) -> Self {
+        self.scale_x.set(scale.into());
+        self
+    }
+
+    /// Set the scale of the Y axis e.g., [Scale::Log10]. Pair with [TickLabels::log_floats](crate::TickLabels::log_floats) for log ticks.
+    pub fn with_scale_y(self, scale: impl Into<Scale>) -> Self {
+        self.scale_y.set(scale.into());
+        self
+    }
+
     /// Adds a line to the series. See [Line] for more details.
     pub fn line(mut self, line: impl Into<Line<T, Y>>) -> Self {
         self.series.push(Rc::new(line.into()));
@@ -228,6 +367,57 @@ impl<T, X, Y> Series<T, X, Y> {
         self
     }
 
+    /// Adds a scatter to the series. See [Scatter] for more details.
+    pub fn scatter(mut self, scatter: impl Into<Scatter<T, Y>>) -> Self {
+        self.series.push(Rc::new(scatter.into()));
+        self
+    }
+
+    /// Adds multiple scatters to the series at once. This is equivalent to calling [scatter](Self::scatter) multiple times.
+    pub fn scatters(
+        mut self,
+        scatters: impl IntoIterator<Item = impl Into<Scatter<T, Y>>>,
+    ) -> Self {
+        for scatter in scatters {
+            self = self.scatter(scatter.into());
+        }
+        self
+    }
+
+    /// Adds a box plot to the series. See [BoxPlot] for more details.
+    pub fn box_plot(mut self, box_plot: impl Into<BoxPlot<T, Y>>) -> Self {
+        self.series.push(Rc::new(box_plot.into()));
+        self
+    }
+
+    /// Adds multiple box plots to the series at once. This is equivalent to calling [box_plot](Self::box_plot) multiple times.
+    pub fn box_plots(
+        mut self,
+        box_plots: impl IntoIterator<Item = impl Into<BoxPlot<T, Y>>>,
+    ) -> Self {
+        for box_plot in box_plots {
+            self = self.box_plot(box_plot.into());
+        }
+        self
+    }
+
+    /// Adds a candlestick to the series. See [Candlestick] for more details.
+    pub fn candlestick(mut self, candlestick: impl Into<Candlestick<T, Y>>) -> Self {
+        self.series.push(Rc::new(candlestick.into()));
+        self
+    }
+
+    /// Adds multiple candlesticks to the series at once. This is equivalent to calling [candlestick](Self::candlestick) multiple times.
+    pub fn candlesticks(
+        mut self,
+        candlesticks: impl IntoIterator<Item = impl Into<Candlestick<T, Y>>>,
+    ) -> Self {
+        for candlestick in candlesticks {
+            self = self.candlestick(candlestick.into());
+        }
+        self
+    }
+
     /// Gets the current size of the series (number of lines and stacks).
     pub fn len(&self) -> usize {
         self.series.len()
@@ -283,15 +473,70 @@ impl<T, Y> SeriesAcc<T, Y> {
         get_y
     }
 
+    fn next_group_id(&mut self) -> usize {
+        let group_id = self.next_group_id;
+        self.next_group_id += 1;
+        group_id
+    }
+
     fn push_bar(&mut self, colour: Memo<Colour>, bar: impl IntoUseBar<T, Y>) -> GetY<T, Y> {
+        let group_id = self.next_group_id();
+        self.push_grouped_bar(colour, group_id, bar)
+    }
+
+    /// Pushes a bar into an existing group. Bars in the same group share the same slot.
+    fn push_grouped_bar(
+        &mut self,
+        colour: Memo<Colour>,
+        group_id: usize,
+        bar: impl IntoUseBar<T, Y>,
+    ) -> GetY<T, Y> {
         // Create bar
         let id = self.next_id;
-        let group_id = self.next_group_id;
         self.next_id += 1;
-        self.next_group_id += 1;
         let (bar, get_y) = bar.into_use_bar(id, group_id, colour);
         // Insert bar
         self.lines.push((bar, get_y.clone()));
         get_y
     }
+
+    fn push_box_plot(
+        &mut self,
+        colour: Memo<Colour>,
+        box_plot: impl IntoUseBoxPlot<T, Y>,
+    ) -> GetY<T, Y> {
+        // Create box plot: shares an X value with bars
+        let id = self.next_id;
+        self.next_id += 1;
+        let group_id = self.next_group_id();
+        let (box_plot, get_y) = box_plot.into_use_box_plot(id, group_id, colour);
+        // Insert box plot
+        self.lines.push((box_plot, get_y.clone()));
+        get_y
+    }
+
+    fn push_candlestick(&mut self, candlestick: impl IntoUseCandlestick<T, Y>) -> GetY<T, Y> {
+        // Create candlestick: shares an X value with bars
+        let id = self.next_id;
+        self.next_id += 1;
+        let group_id = self.next_group_id();
+        let (candlestick, get_y) = candlestick.into_use_candlestick(id, group_id);
+        // Insert candlestick
+        self.lines.push((candlestick, get_y.clone()));
+        get_y
+    }
+
+    fn push_scatter(
+        &mut self,
+        colour: Memo<Colour>,
+        scatter: impl IntoUseScatter<T, Y>,
+    ) -> GetY<T, Y> {
+        // Create scatter
+        let id = self.next_id;
+        self.next_id += 1;
+        let (scatter, get_y) = scatter.into_use_scatter(id, colour);
+        // Insert scatter
+        self.lines.push((scatter, get_y.clone()));
+        get_y
+    }
 }
@@ -0,0 +1,148 @@
+use super::{
+    line::RenderMarkers, ApplyUseSeries, GetYValue, IntoUseScatter, SeriesAcc, UseY, YAxis,
+};
+use crate::{Colour, Marker, MarkerShape, Tick};
+use leptos::*;
+use std::rc::Rc;
+
+/// Draws a point marker for each value on the chart. Points are not connected, so data does not need to be sorted or ordered in any meaningful way.
+///
+/// # Example
+/// ```rust
+/// # use leptos_chartistry::*;
+/// # struct MyData { x: f64, y1: f64, y2: f64 }
+/// let series = Series::new(|data: &MyData| data.x)
+///     .scatter(Scatter::new(|data: &MyData| data.y1).with_name("readings"))
+///     .scatter(
+///         Scatter::new(|data: &MyData| data.y2)
+///             .with_name("calibration")
+///             .with_marker(MarkerShape::Cross),
+///     );
+/// ```
+/// See this in action with the [scatter chart example](https://feral-dot-io.github.io/leptos-chartistry/examples.html#scatter-chart).
+pub struct Scatter<T, Y> {
+    get_y: Rc<dyn GetYValue<T, Y>>,
+    /// Name of the scatter. Used in the legend and tooltip.
+    pub name: RwSignal<String>,
+    /// Colour of the points. If not set, the next colour in the series will be used.
+    pub colour: RwSignal<Option<Colour>>,
+    /// Width of the (imaginary) line each marker is sized against. See [Marker::scale]. Default is 1.0.
+    pub width: RwSignal<f64>,
+    /// Marker drawn at each point. Default is a [circle](MarkerShape::Circle).
+    pub marker: Marker,
+    /// Which Y axis the scatter is projected against. Default is [YAxis::Primary].
+    pub y_axis: RwSignal<YAxis>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UseScatter {
+    colour: Signal<Colour>,
+    width: RwSignal<f64>,
+    marker: Marker,
+}
+
+impl<T, Y> Scatter<T, Y> {
+    /// Create a new scatter. The `get_y` function is used to extract the Y value from your struct.
+    ///
+    /// See the module documentation for examples.
+    pub fn new(get_y: impl Fn(&T) -> Y + 'static) -> Self
+    where
+        Y: Tick,
+    {
+        Self {
+            get_y: Rc::new(get_y),
+            name: RwSignal::default(),
+            colour: RwSignal::default(),
+            width: create_rw_signal(1.0),
+            marker: Marker::from_shape(MarkerShape::Circle),
+            y_axis: RwSignal::default(),
+        }
+    }
+
+    /// Set the name of the scatter. Used in the legend and tooltip.
+    pub fn with_name(self, name: impl Into<String>) -> Self {
+        self.name.set(name.into());
+        self
+    }
+
+    /// Set the colour of the points. If not set, the next colour in the series will be used.
+    pub fn with_colour(self, colour: impl Into<Option<Colour>>) -> Self {
+        self.colour.set(colour.into());
+        self
+    }
+
+    /// Set the width that markers are sized against. See [Marker::scale].
+    pub fn with_width(self, width: impl Into<f64>) -> Self {
+        self.width.set(width.into());
+        self
+    }
+
+    /// Set the marker drawn at each point.
+    pub fn with_marker(mut self, marker: impl Into<Marker>) -> Self {
+        self.marker = marker.into();
+        self
+    }
+
+    /// Set which Y axis the scatter is projected against.
+    pub fn with_y_axis(self, y_axis: impl Into<YAxis>) -> Self {
+        self.y_axis.set(y_axis.into());
+        self
+    }
+}
+
+impl<T, Y> Clone for Scatter<T, Y> {
+    fn clone(&self) -> Self {
+        Self {
+            get_y: self.get_y.clone(),
+            name: self.name,
+            colour: self.colour,
+            width: self.width,
+            marker: self.marker.clone(),
+            y_axis: self.y_axis,
+        }
+    }
+}
+
+impl<T, Y: Tick, F: Fn(&T) -> Y + 'static> From<F> for Scatter<T, Y> {
+    fn from(f: F) -> Self {
+        Self::new(f)
+    }
+}
+
+impl<T, Y> ApplyUseSeries<T, Y> for Scatter<T, Y> {
+    fn apply_use_series(self: Rc<Self>, series: &mut SeriesAcc<T, Y>) {
+        let colour = series.next_colour();
+        _ = series.push_scatter(colour, (*self).clone());
+    }
+}
+
+impl<T, Y> IntoUseScatter<T, Y> for Scatter<T, Y> {
+    fn into_use_scatter(self, id: usize, colour: Memo<Colour>) -> (UseY, Rc<dyn GetYValue<T, Y>>) {
+        let override_colour = self.colour;
+        let colour = Signal::derive(move || override_colour.get().unwrap_or(colour.get()));
+        let scatter = UseY::new_scatter(
+            id,
+            self.name,
+            self.y_axis,
+            UseScatter {
+                colour,
+                width: self.width,
+                marker: self.marker.clone(),
+            },
+        );
+        (scatter, self.get_y.clone())
+    }
+}
+
+#[component]
+pub fn RenderScatter(scatter: UseScatter, positions: Signal<Vec<(f64, f64)>>) -> impl IntoView {
+    view! {
+        <g class="_chartistry_scatter">
+            <RenderMarkers
+                marker=scatter.marker
+                colour=scatter.colour
+                line_width=scatter.width
+                positions=positions />
+        </g>
+    }
+}
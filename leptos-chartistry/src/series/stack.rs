@@ -1,7 +1,7 @@
-use super::{ApplyUseSeries, GetYValue, IntoUseLine, SeriesAcc, UseY};
+use super::{ApplyUseSeries, GetYValue, IntoUseBar, IntoUseLine, SeriesAcc, UseY};
 use crate::{
     colours::{Colour, ColourScheme, BATLOW},
-    Line,
+    Bar, FillBaseline, Line,
 };
 use leptos::signal_prelude::*;
 use std::ops::Add;
@@ -10,7 +10,7 @@ use std::rc::Rc;
 /// Default colour scheme for stack. Assumes a light background with dark values for high values.
 pub const STACK_COLOUR_SCHEME: [Colour; 10] = BATLOW;
 
-/// Draws a stack of lines on top of each other.
+/// Draws a stack of lines (or bars) on top of each other.
 ///
 /// # Example
 /// ```rust
@@ -21,11 +21,31 @@ pub const STACK_COLOUR_SCHEME: [Colour; 10] = BATLOW;
 ///     .line(Line::new(|data: &MyData| data.y2).with_name("pixies"));
 /// ```
 /// See this in action with the [stacked line chart example](https://feral-dot-io.github.io/leptos-chartistry/examples.html#stacked-line-chart).
+///
+/// Use [Stack::with_fill] to shade each layer for a stacked area chart.
+///
+/// # Stacked bars
+/// Bars in a stack share the same slot with each bar starting from the top of the previous layer:
+/// ```rust
+/// # use leptos_chartistry::*;
+/// # struct MyData { x: f64, y1: f64, y2: f64 }
+/// let stack = Stack::new()
+///     .bar(Bar::new(|data: &MyData| data.y1).with_name("fairies"))
+///     .bar(Bar::new(|data: &MyData| data.y2).with_name("pixies"));
+/// ```
+/// See this in action with the [stacked bar chart example](https://feral-dot-io.github.io/leptos-chartistry/examples.html#stacked-bar-chart).
 #[derive(Clone)]
 pub struct Stack<T, Y> {
-    lines: Vec<Line<T, Y>>,
+    layers: Vec<Layer<T, Y>>,
     /// Colour scheme for the stack. Interpolates colours across the whole scheme.
     pub colours: RwSignal<ColourScheme>,
+    /// Whether to fill each layer down to the layer below it (or zero for the first layer). Overrides each line's [fill baseline](crate::Fill::baseline). Default is false.
+    pub fill: RwSignal<bool>,
+}
+
+enum Layer<T, Y> {
+    Line(Line<T, Y>),
+    Bar(Bar<T, Y>),
 }
 
 impl<T, Y> Stack<T, Y> {
@@ -36,18 +56,24 @@ impl<T, Y> Stack<T, Y> {
 
     /// Adds a line to the stack.
     pub fn line(mut self, line: impl Into<Line<T, Y>>) -> Self {
-        self.lines.push(line.into());
+        self.layers.push(Layer::Line(line.into()));
+        self
+    }
+
+    /// Adds a bar to the stack. Starts from the top of the previous layer.
+    pub fn bar(mut self, bar: impl Into<Bar<T, Y>>) -> Self {
+        self.layers.push(Layer::Bar(bar.into()));
         self
     }
 
-    /// Gets the current number of lines in the stack.
+    /// Gets the current number of lines and bars in the stack.
     pub fn len(&self) -> usize {
-        self.lines.len()
+        self.layers.len()
     }
 
-    /// Returns true if there are no lines in the stack.
+    /// Returns true if there are no lines or bars in the stack.
     pub fn is_empty(&self) -> bool {
-        self.lines.is_empty()
+        self.layers.is_empty()
     }
 
     /// Sets the colour scheme for the stack.
@@ -55,13 +81,20 @@ impl<T, Y> Stack<T, Y> {
         self.colours.set(colours.into());
         self
     }
+
+    /// Sets whether to fill each layer of the stack. Creates a stacked area chart.
+    pub fn with_fill(self, fill: impl Into<bool>) -> Self {
+        self.fill.set(fill.into());
+        self
+    }
 }
 
 impl<T, Y> Default for Stack<T, Y> {
     fn default() -> Self {
         Self {
-            lines: Vec::new(),
+            layers: Vec::new(),
             colours: ColourScheme::from(STACK_COLOUR_SCHEME).invert().into(),
+            fill: RwSignal::default(),
         }
     }
 }
@@ -76,16 +109,53 @@ impl<T, Y, I: IntoIterator<Item = Line<T, Y>>> From<I> for Stack<T, Y> {
     }
 }
 
+impl<T, Y> Clone for Layer<T, Y> {
+    fn clone(&self) -> Self {
+        match self {
+            Layer::Line(line) => Layer::Line(line.clone()),
+            Layer::Bar(bar) => Layer::Bar(bar.clone()),
+        }
+    }
+}
+
 impl<T: 'static, Y: std::ops::Add<Output = Y> + 'static> ApplyUseSeries<T, Y> for Stack<T, Y> {
     fn apply_use_series(self: Rc<Self>, series: &mut SeriesAcc<T, Y>) {
         let colours = self.colours;
+        let fill = self.fill;
         let mut previous = None;
-        let total_lines = self.lines.len();
-        for (id, line) in self.lines.clone().into_iter().enumerate() {
-            let colour = create_memo(move |_| colours.get().interpolate(id, total_lines));
-            let line = StackedLine::new(line, previous.clone());
-            let get_y = series.push_line(colour, line);
-            // Sum next line with this one
+        let mut previous_id = None;
+        // All bars in a stack share a group
+        let mut group_id = None;
+        let total_layers = self.layers.len();
+        for (id, layer) in self.layers.clone().into_iter().enumerate() {
+            let colour = create_memo(move |_| colours.get().interpolate(id, total_layers));
+            let this_id = series.next_id;
+            let get_y = match layer {
+                Layer::Line(line) => {
+                    // Fill down to the previous layer
+                    let line_baseline = line.fill.baseline;
+                    let fill_baseline = Signal::derive(move || {
+                        if fill.get() {
+                            Some(previous_id.map_or(FillBaseline::Zero, FillBaseline::Line))
+                        } else {
+                            line_baseline.get()
+                        }
+                    });
+                    let line = StackedLine::new(line, previous.clone(), fill_baseline);
+                    series.push_line(colour, line)
+                }
+                Layer::Bar(bar) => {
+                    let group_id = *group_id.get_or_insert_with(|| series.next_group_id());
+                    let bar = StackedBar {
+                        bar,
+                        previous: previous.clone(),
+                        stacked_on: previous_id,
+                    };
+                    series.push_grouped_bar(colour, group_id, bar)
+                }
+            };
+            // Sum next layer with this one
+            previous_id = Some(this_id);
             previous = Some(get_y);
         }
     }
@@ -95,6 +165,14 @@ impl<T: 'static, Y: std::ops::Add<Output = Y> + 'static> ApplyUseSeries<T, Y> fo
 struct StackedLine<T, Y> {
     line: Line<T, Y>,
     previous: Option<Rc<dyn GetYValue<T, Y>>>,
+    fill_baseline: Signal<Option<FillBaseline>>,
+}
+
+#[derive(Clone)]
+struct StackedBar<T, Y> {
+    bar: Bar<T, Y>,
+    previous: Option<Rc<dyn GetYValue<T, Y>>>,
+    stacked_on: Option<usize>,
 }
 
 #[derive(Clone)]
@@ -104,14 +182,24 @@ struct UseStackLine<T, Y> {
 }
 
 impl<T, Y> StackedLine<T, Y> {
-    pub fn new(line: Line<T, Y>, previous: Option<Rc<dyn GetYValue<T, Y>>>) -> Self {
-        Self { line, previous }
+    pub fn new(
+        line: Line<T, Y>,
+        previous: Option<Rc<dyn GetYValue<T, Y>>>,
+        fill_baseline: Signal<Option<FillBaseline>>,
+    ) -> Self {
+        Self {
+            line,
+            previous,
+            fill_baseline,
+        }
     }
 }
 
 impl<T: 'static, Y: Add<Output = Y> + 'static> IntoUseLine<T, Y> for StackedLine<T, Y> {
     fn into_use_line(self, id: usize, colour: Memo<Colour>) -> (UseY, Rc<dyn GetYValue<T, Y>>) {
-        let (line, get_y) = self.line.into_use_line(id, colour);
+        let (line, get_y) = self
+            .line
+            .into_use_line_with_baseline(id, colour, self.fill_baseline);
         let get_y = Rc::new(UseStackLine {
             current: get_y,
             previous: self.previous.clone(),
@@ -120,6 +208,24 @@ impl<T: 'static, Y: Add<Output = Y> + 'static> IntoUseLine<T, Y> for StackedLine
     }
 }
 
+impl<T: 'static, Y: Add<Output = Y> + 'static> IntoUseBar<T, Y> for StackedBar<T, Y> {
+    fn into_use_bar(
+        self,
+        id: usize,
+        group_id: usize,
+        colour: Memo<Colour>,
+    ) -> (UseY, Rc<dyn GetYValue<T, Y>>) {
+        let (bar, get_y) = self
+            .bar
+            .into_use_bar_stacked_on(id, group_id, colour, self.stacked_on);
+        let get_y = Rc::new(UseStackLine {
+            current: get_y,
+            previous: self.previous.clone(),
+        });
+        (bar, get_y)
+    }
+}
+
 impl<T, Y: Add<Output = Y>> GetYValue<T, Y> for UseStackLine<T, Y> {
     fn value(&self, t: &T) -> Y {
         self.current.value(t)
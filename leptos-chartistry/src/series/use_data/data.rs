@@ -9,14 +9,20 @@ use std::collections::HashMap;
 pub struct Data<X, Y> {
     data_x: Vec<X>,
     data_y: Vec<HashMap<usize, Y>>,
+    // Extra Y values of series that have them e.g., candlesticks
+    data_extra_y: Vec<HashMap<usize, Vec<Y>>>,
 
     // Data index: X position to data
     x_to_data: Vec<f64>,
+    // Data index to the original data (and coords) index. Differs if unsorted
+    data_index: Vec<usize>,
     // Rendering data
     coords: HashMap<usize, Vec<(f64, f64)>>,
+    extra_coords: HashMap<usize, Vec<Vec<f64>>>,
 
     range_x: Range<X>,
-    range_y: Range<Y>,
+    // Y range of each series
+    range_y: HashMap<usize, Range<Y>>,
 }
 
 impl<X: Tick, Y: Tick> Data<X, Y> {
@@ -28,13 +34,16 @@ impl<X: Tick, Y: Tick> Data<X, Y> {
         let mut built = Self {
             data_x: Vec::with_capacity(cap),
             data_y: Vec::with_capacity(cap),
+            data_extra_y: Vec::with_capacity(cap),
             x_to_data: Vec::with_capacity(cap * y_cap),
+            data_index: (0..cap).collect(),
             coords: HashMap::with_capacity(cap),
+            extra_coords: HashMap::new(),
             range_x: Range::default(),
-            range_y: Range::default(),
+            range_y: HashMap::with_capacity(y_cap),
         };
 
-        for datum in data {
+        for (index, datum) in data.iter().enumerate() {
             // X
             let x = (get_x)(datum);
             let x_position = x.position();
@@ -43,11 +52,12 @@ impl<X: Tick, Y: Tick> Data<X, Y> {
 
             // Y
             let mut y_data = HashMap::with_capacity(y_cap);
+            let mut y_extra = HashMap::new();
             for (&id, get_y) in &get_ys {
                 let y = get_y.value(datum);
                 // Note: cumulative can differ from Y when stacked
                 let y_cumulative = get_y.cumulative_value(datum);
-                built.range_y.update(&y_cumulative);
+                built.range_y.entry(id).or_default().update(&y_cumulative);
                 // Insert
                 y_data.insert(id, y);
                 built
@@ -55,16 +65,48 @@ impl<X: Tick, Y: Tick> Data<X, Y> {
                     .entry(id)
                     .or_insert_with(|| Vec::with_capacity(cap))
                     .push((x_position, y_cumulative.position()));
+
+                // Extra Y values. Only kept for series that have them
+                let extra = get_y.extra_values(datum);
+                if !extra.is_empty() || built.extra_coords.contains_key(&id) {
+                    let range = built.range_y.entry(id).or_default();
+                    extra.iter().for_each(|y| range.update(y));
+                    built
+                        .extra_coords
+                        .entry(id)
+                        .or_insert_with(|| vec![Vec::new(); index])
+                        .push(extra.iter().map(Tick::position).collect());
+                    y_extra.insert(id, extra);
+                }
             }
 
             // Insert
             built.data_x.push(x);
             built.data_y.push(y_data);
+            built.data_extra_y.push(y_extra);
         }
 
+        built.sort_index();
         built
     }
 
+    /// Sorts our data index by X position. Unsorted data (e.g., a scatter) still renders in the order given but nearest lookups need sorted positions. Coords are left as-is.
+    fn sort_index(&mut self) {
+        if self.x_to_data.windows(2).all(|w| w[0] <= w[1]) {
+            return;
+        }
+        let mut order = (0..self.x_to_data.len()).collect::<Vec<_>>();
+        order.sort_by(|&a, &b| self.x_to_data[a].total_cmp(&self.x_to_data[b]));
+        self.x_to_data = order.iter().map(|&i| self.x_to_data[i]).collect();
+        self.data_x = order.iter().map(|&i| self.data_x[i].clone()).collect();
+        self.data_y = order.iter().map(|&i| self.data_y[i].clone()).collect();
+        self.data_extra_y = order
+            .iter()
+            .map(|&i| self.data_extra_y[i].clone())
+            .collect();
+        self.data_index = order;
+    }
+
     pub fn len(&self) -> usize {
         self.data_x.len()
     }
@@ -73,8 +115,12 @@ impl<X: Tick, Y: Tick> Data<X, Y> {
         self.range_x.clone()
     }
 
-    pub fn range_y(&self) -> Range<Y> {
-        self.range_y.clone()
+    /// Returns the Y range across the given series.
+    pub fn range_y(&self, include: impl Fn(usize) -> bool) -> Range<Y> {
+        self.range_y
+            .iter()
+            .filter(|(&id, _)| include(id))
+            .fold(Range::default(), |acc, (_, range)| acc.union(range))
     }
 
     /// Finds the index of the _nearest_ position to the given X. Returns None if no data.
@@ -103,6 +149,12 @@ impl<X: Tick, Y: Tick> Data<X, Y> {
         }
     }
 
+    /// Index of the given (original order) data nearest to the given X position.
+    pub fn nearest_data_index(&self, pos_x: f64) -> Option<usize> {
+        self.nearest_index(pos_x)
+            .map(|index| self.data_index[index])
+    }
+
     pub fn nearest_data_x(&self, pos_x: f64) -> Option<X> {
         self.nearest_index(pos_x)
             .map(|index| self.data_x[index].clone())
@@ -114,6 +166,26 @@ impl<X: Tick, Y: Tick> Data<X, Y> {
             .unwrap_or_default()
     }
 
+    /// Extra Y values of each series (that has them) at the nearest X position.
+    pub fn nearest_extra_y(&self, pos_x: f64) -> HashMap<usize, Vec<Y>> {
+        self.nearest_index(pos_x)
+            .map(|index| self.data_extra_y[index].clone())
+            .unwrap_or_default()
+    }
+
+    /// Rendered Y positions (cumulative if stacked) of each series at the nearest X position.
+    pub fn nearest_positions_y(&self, pos_x: f64) -> HashMap<usize, f64> {
+        self.nearest_index(pos_x)
+            .map(|index| {
+                let index = self.data_index[index];
+                self.coords
+                    .iter()
+                    .map(|(&id, coords)| (id, coords[index].1))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
     /// Given an arbitrary (unaligned to data) X position, find the nearest X position aligned to data. Returns `f64::NAN` if no data. Note a position covers a range dependent on the chart width.
     pub fn nearest_position_x(&self, pos_x: f64) -> Option<f64> {
         self.nearest_index(pos_x).map(|index| self.x_to_data[index])
@@ -122,11 +194,17 @@ impl<X: Tick, Y: Tick> Data<X, Y> {
     pub fn series_positions(&self, id: usize) -> Vec<(f64, f64)> {
         self.coords.get(&id).cloned().unwrap_or_default()
     }
+
+    /// Extra Y positions of a series for each data. Empty if the series has none.
+    pub fn series_extra_positions(&self, id: usize) -> Vec<Vec<f64>> {
+        self.extra_coords.get(&id).cloned().unwrap_or_default()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::series::GetYValue;
     use std::rc::Rc;
 
     #[derive(Clone, Debug, PartialEq)]
@@ -181,8 +259,16 @@ mod tests {
         // Ranges
         assert_eq!(data.range_x.range(), Some((&1.0, &7.0)));
         assert_eq!(data.range_x.positions(), Some((1.0, 7.0)));
-        assert_eq!(data.range_y.range(), Some((&2.0, &9.0)));
-        assert_eq!(data.range_y.positions(), Some((2.0, 9.0)));
+        assert_eq!(data.range_y(|_| true).range(), Some((&2.0, &9.0)));
+        assert_eq!(data.range_y(|_| true).positions(), Some((2.0, 9.0)));
+    }
+
+    #[test]
+    fn test_range_y_subset() {
+        let data = test_data(DATA);
+        assert_eq!(data.range_y(|id| id == 66).range(), Some((&2.0, &8.0)));
+        assert_eq!(data.range_y(|id| id == 5).range(), Some((&3.0, &9.0)));
+        assert_eq!(data.range_y(|_| false).range(), None);
     }
 
     #[test]
@@ -200,6 +286,34 @@ mod tests {
         assert_eq!(data.nearest_index(6.5), Some(2));
     }
 
+    #[test]
+    fn test_nearest_unsorted() {
+        let data = test_data(&[
+            MyData::new(7.0, 8.0, 9.0),
+            MyData::new(1.0, 2.0, 3.0),
+            MyData::new(4.0, 5.0, 6.0),
+        ]);
+        // Index is sorted
+        assert_eq!(data.x_to_data, vec![1.0, 4.0, 7.0]);
+        assert_eq!(data.nearest_data_x(3.0), Some(4.0));
+        assert_eq!(data.nearest_data_index(3.0), Some(2));
+        assert_eq!(data.nearest_data_index(0.0), Some(1));
+        assert_eq!(
+            data.nearest_data_y(8.0),
+            HashMap::from([(66, 8.0), (5, 9.0)])
+        );
+        assert_eq!(
+            data.nearest_positions_y(8.0),
+            HashMap::from([(66, 8.0), (5, 9.0)])
+        );
+        // Coords keep the given order
+        assert_eq!(data.data_index, vec![1, 2, 0]);
+        assert_eq!(
+            data.series_positions(66),
+            vec![(7.0, 8.0), (1.0, 2.0), (4.0, 5.0)]
+        );
+    }
+
     #[test]
     fn test_nearest_index_empty() {
         let data = test_data(&[]);
@@ -215,6 +329,16 @@ mod tests {
         assert_eq!(data.nearest_data_x(4.0), Some(4.0));
     }
 
+    #[test]
+    fn test_nearest_positions_y() {
+        let data = test_data(DATA);
+        assert_eq!(
+            data.nearest_positions_y(3.0),
+            HashMap::from([(66, 5.0), (5, 6.0)])
+        );
+        assert!(test_data(&[]).nearest_positions_y(3.0).is_empty());
+    }
+
     #[test]
     fn test_nearest_aligned_position_x() {
         let data = test_data(DATA);
@@ -223,4 +347,50 @@ mod tests {
         assert_eq!(data.nearest_position_x(3.0), Some(4.0));
         assert_eq!(data.nearest_position_x(4.0), Some(4.0));
     }
+
+    struct GetSpread;
+
+    impl GetYValue<MyData, f64> for GetSpread {
+        fn value(&self, d: &MyData) -> f64 {
+            d.y1
+        }
+
+        fn cumulative_value(&self, d: &MyData) -> f64 {
+            d.y1
+        }
+
+        fn extra_values(&self, d: &MyData) -> Vec<f64> {
+            vec![d.y1 - d.y2, d.y1 + d.y2]
+        }
+    }
+
+    #[test]
+    fn test_extra_values() {
+        let mut get_ys = HashMap::<usize, GetY<_, _>>::new();
+        get_ys.insert(66, Rc::new(GetSpread));
+        get_ys.insert(5, Rc::new(|d: &MyData| d.y2));
+        let data = Data::new(
+            Rc::new(|d: &MyData| d.x),
+            get_ys,
+            &[MyData::new(4.0, 5.0, 1.0), MyData::new(1.0, 2.0, 3.0)],
+        );
+        // Extras are included in the range
+        assert_eq!(data.range_y(|id| id == 66).range(), Some((&-1.0, &6.0)));
+        assert_eq!(data.range_y(|id| id == 5).range(), Some((&1.0, &3.0)));
+        // Only series with extras
+        assert_eq!(
+            data.nearest_extra_y(3.0),
+            HashMap::from([(66, vec![4.0, 6.0])])
+        );
+        assert_eq!(
+            data.nearest_extra_y(0.0),
+            HashMap::from([(66, vec![-1.0, 5.0])])
+        );
+        // Positions keep the given order
+        assert_eq!(
+            data.series_extra_positions(66),
+            vec![vec![4.0, 6.0], vec![-1.0, 5.0]]
+        );
+        assert!(data.series_extra_positions(5).is_empty());
+    }
 }
@@ -4,25 +4,58 @@ mod range;
 pub use range::Range;
 
 use crate::{
+    projection::Projection,
     series::{use_y::RenderUseY, UseY},
     state::State,
-    Series, Tick,
+    BarOrientation, Scale, Series, Tick, YAxis,
 };
 use data::Data;
 use leptos::*;
+use std::collections::{HashMap, HashSet};
+
+/// Data nearest to the mouse. Passed to the [Chart](crate::Chart) `on_click` and `on_hover` callbacks.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct DataPoint<X, Y> {
+    /// Index of the `T` in the chart's data.
+    pub index: usize,
+    /// X value.
+    pub x: X,
+    /// Name and Y value of each visible series. Sorted by name.
+    pub y: Vec<(String, Y)>,
+}
 
 #[derive(Clone)]
 pub struct UseData<X: 'static, Y: 'static> {
     data: Memo<Data<X, Y>>,
-    pub len: Memo<usize>,
+    /// Number of evenly spaced X values that fit across the visible X range. Used to size bars. Fewer than the data's length when zoomed in.
+    pub slots_x: Memo<f64>,
+    /// Like `slots_x` for the Y range. Used to size horizontal bars.
+    pub slots_y: Memo<f64>,
+    /// All series, including hidden. Sorted by name
     pub series: Memo<Vec<UseY>>,
+    /// Series that aren't hidden. Sorted by name
+    pub visible: Memo<Vec<UseY>>,
+    pub hidden: RwSignal<HashSet<usize>>,
     pub includes_bars: Memo<bool>,
+    pub includes_horizontal_bars: Memo<bool>,
     pub range_x: Memo<Range<X>>,
     pub range_y: Memo<Range<Y>>,
+    pub range_y2: Memo<Range<Y>>,
+    pub zoom_x: RwSignal<Option<(X, X)>>,
+    pub zoom_y: RwSignal<Option<(Y, Y)>>,
+    pub scale_x: RwSignal<Scale>,
+    pub scale_y: RwSignal<Scale>,
 }
 
 impl<X: Tick, Y: Tick> UseData<X, Y> {
-    pub fn new<T: 'static>(series: Series<T, X, Y>, data: Signal<Vec<T>>) -> UseData<X, Y> {
+    /// Builds data for a series. The X and Y ranges are extended to include `extend_x` and `extend_y` e.g., reference lines.
+    pub fn new<T: 'static>(
+        series: Series<T, X, Y>,
+        data: Signal<Vec<T>>,
+        extend_x: Signal<Vec<X>>,
+        extend_y: Signal<Vec<Y>>,
+    ) -> UseData<X, Y> {
         let lines = series.to_use_lines();
 
         // Data values
@@ -45,14 +78,79 @@ impl<X: Tick, Y: Tick> UseData<X, Y> {
         };
 
         // Range signals
-        let range_x: Memo<Range<X>> = create_memo(move |_| {
+        let unzoomed_x: Memo<Range<X>> = create_memo(move |_| {
             data.with(|data| data.range_x())
                 .maybe_update(vec![series.min_x.get(), series.max_x.get()])
+                .maybe_update(extend_x.get().into_iter().map(Some).collect())
         });
-        let range_y: Memo<Range<Y>> = create_memo(move |_| {
-            data.with(|data| data.range_y())
-                .maybe_update(vec![series.min_y.get(), series.max_y.get()])
+        let range_x = {
+            // Zoom clips the range
+            let zoom_x = series.zoom_x;
+            create_memo(move |_| match zoom_x.get() {
+                Some((first, last)) => Range::default().maybe_update(vec![Some(first), Some(last)]),
+                None => unzoomed_x.get(),
+            })
+        };
+        let hidden = series.hidden;
+        let y_axes = lines
+            .iter()
+            .map(|(use_y, _)| use_y.clone())
+            .collect::<Vec<_>>();
+        let range_y = use_range_y(
+            data,
+            y_axes.clone(),
+            hidden,
+            YAxis::Primary,
+            series.min_y,
+            series.max_y,
+        );
+        let unzoomed_y = create_memo(move |_| {
+            range_y
+                .get()
+                .maybe_update(extend_y.get().into_iter().map(Some).collect())
         });
+        let range_y = {
+            let zoom_y = series.zoom_y;
+            create_memo(move |_| match zoom_y.get() {
+                Some((first, last)) => Range::default().maybe_update(vec![Some(first), Some(last)]),
+                None => unzoomed_y.get(),
+            })
+        };
+        let range_y2 = use_range_y(
+            data,
+            y_axes,
+            hidden,
+            YAxis::Secondary,
+            series.min_y2,
+            series.max_y2,
+        );
+        // Zoom the secondary axis by the same proportion as the primary
+        let range_y2 = {
+            let (zoom_y, scale_y) = (series.zoom_y, series.scale_y);
+            create_memo(move |_| {
+                let range_y2 = range_y2.get();
+                let zoomed = zoom_y.get().and_then(|(first, last)| {
+                    let scale = scale_y.get();
+                    let (first, last) = zoom_proportionally(
+                        scale,
+                        (first.position(), last.position()),
+                        unzoomed_y.with(|range| range.positions_for(scale))?,
+                        range_y2.positions_for(scale)?,
+                    )?;
+                    let (reference, _) = range_y2.range()?;
+                    let (first, last) =
+                        (reference.at_position(first)?, reference.at_position(last)?);
+                    Some(Range::default().maybe_update(vec![Some(first), Some(last)]))
+                });
+                zoomed.unwrap_or(range_y2)
+            })
+        };
+
+        let (scale_x, scale_y) = (series.scale_x, series.scale_y);
+        let (zoom_x, zoom_y) = (series.zoom_x, series.zoom_y);
+        let len = create_memo(move |_| with!(|data| data.len()));
+        let slots_x = use_slots(len, unzoomed_x, range_x, scale_x);
+        let slots_y = use_slots(len, unzoomed_y, range_y, scale_y);
 
         // Sort series by name
         let series = {
@@ -63,16 +161,30 @@ impl<X: Tick, Y: Tick> UseData<X, Y> {
                 lines
             })
         };
-        let includes_bars =
-            create_memo(move |_| series.get().iter().any(|use_y| use_y.bar().is_some()));
+        let visible = create_memo(move |_| {
+            let mut series = series.get();
+            hidden.with(|hidden| series.retain(|use_y| !hidden.contains(&use_y.id)));
+            series
+        });
+        let includes_bars = use_includes_bars(visible, BarOrientation::Vertical);
+        let includes_horizontal_bars = use_includes_bars(visible, BarOrientation::Horizontal);
 
         UseData {
             data,
-            len: create_memo(move |_| with!(|data| data.len())),
+            slots_x,
+            slots_y,
             series,
+            visible,
+            hidden,
             includes_bars,
+            includes_horizontal_bars,
             range_x,
             range_y,
+            range_y2,
+            zoom_x,
+            zoom_y,
+            scale_x,
+            scale_y,
         }
     }
 }
@@ -88,9 +200,66 @@ impl<X: Tick, Y: Tick> UseData<X, Y> {
         create_memo(move |_| data.with(|data| data.nearest_position_x(pos_x.get())))
     }
 
+    /// Returns the SVG coords of a series. Empty if the series doesn't exist.
+    pub fn svg_positions(&self, id: usize, proj: &Projection) -> Vec<(f64, f64)> {
+        self.data.with(|data| {
+            data.series_positions(id)
+                .into_iter()
+                .map(|(x, y)| proj.position_to_svg(x, y))
+                .collect::<Vec<_>>()
+        })
+    }
+
+    /// Returns the extra SVG Y coords of a series for each data e.g., a candlestick's open, high and low. Empty if the series has none.
+    pub fn svg_extra_positions(&self, id: usize, proj: &Projection) -> Vec<Vec<f64>> {
+        self.data.with(|data| {
+            data.series_extra_positions(id)
+                .into_iter()
+                .map(|ys| {
+                    ys.into_iter()
+                        .map(|y| proj.position_to_svg(0.0, y).1)
+                        .collect()
+                })
+                .collect()
+        })
+    }
+
+    /// Returns the data point nearest to the given X position. None if no data.
+    pub fn nearest_data_point(&self, pos_x: Memo<f64>) -> Memo<Option<DataPoint<X, Y>>> {
+        let (data, series) = (self.data, self.visible);
+        create_memo(move |_| {
+            let pos_x = pos_x.get();
+            let (index, x, mut y_values) = data.with(|data| {
+                Some((
+                    data.nearest_data_index(pos_x)?,
+                    data.nearest_data_x(pos_x)?,
+                    data.nearest_data_y(pos_x),
+                ))
+            })?;
+            let y = series.with(|series| {
+                series
+                    .iter()
+                    .filter_map(|use_y| Some((use_y.name.get(), y_values.remove(&use_y.id)?)))
+                    .collect()
+            });
+            Some(DataPoint { index, x, y })
+        })
+    }
+
+    /// Returns the Y positions of each series at the nearest X position.
+    pub fn nearest_positions_y(&self, pos_x: f64) -> HashMap<usize, f64> {
+        self.data.with(|data| data.nearest_positions_y(pos_x))
+    }
+
+    /// Returns the extra Y values of each series (that has them) at the nearest X position.
+    pub fn nearest_extra_y(&self, pos_x: Memo<f64>) -> Memo<HashMap<usize, Vec<Y>>> {
+        let data = self.data;
+        create_memo(move |_| data.with(|data| data.nearest_extra_y(pos_x.get())))
+    }
+
     // TODO: this can never be None
     pub fn nearest_data_y(&self, pos_x: Memo<f64>) -> Memo<Vec<(UseY, Option<Y>)>> {
-        let series = self.series;
+        let series = self.visible;
         let data = self.data;
         create_memo(move |_| {
             let y_values = data.with(|data| data.nearest_data_y(pos_x.get()));
@@ -106,29 +275,222 @@ impl<X: Tick, Y: Tick> UseData<X, Y> {
     }
 }
 
+impl<X, Y: Clone> UseData<X, Y> {
+    /// Returns the Y range of the given axis.
+    pub fn range_y_for(&self, y_axis: Signal<YAxis>) -> Signal<Range<Y>> {
+        let (range_y, range_y2) = (self.range_y, self.range_y2);
+        Signal::derive(move || match y_axis.get() {
+            YAxis::Secondary => range_y2.get(),
+            _ => range_y.get(),
+        })
+    }
+}
+
+/// Y range of visible series on the given axis. Extended by the axis's min and max.
+fn use_range_y<X: Tick, Y: Tick>(
+    data: Memo<Data<X, Y>>,
+    series: Vec<UseY>,
+    hidden: RwSignal<HashSet<usize>>,
+    y_axis: YAxis,
+    min_y: RwSignal<Option<Y>>,
+    max_y: RwSignal<Option<Y>>,
+) -> Memo<Range<Y>> {
+    create_memo(move |_| {
+        let ids = hidden.with(|hidden| {
+            series
+                .iter()
+                .filter(|use_y| use_y.y_axis.get() == y_axis)
+                .filter(|use_y| !hidden.contains(&use_y.id))
+                .map(|use_y| use_y.id)
+                .collect::<Vec<_>>()
+        });
+        data.with(|data| data.range_y(|id| ids.contains(&id)))
+            .maybe_update(vec![min_y.get(), max_y.get()])
+    })
+}
+
+/// Maps a zoomed range of one axis (with the full range) proportionally on to another axis's range. Positions are compared on the given scale. Returns `None` if the ranges can't be compared e.g., the full range is empty.
+fn zoom_proportionally(
+    scale: Scale,
+    zoom: (f64, f64),
+    full: (f64, f64),
+    other: (f64, f64),
+) -> Option<(f64, f64)> {
+    let [zoom_first, zoom_last, full_first, full_last, other_first, other_last] =
+        [zoom.0, zoom.1, full.0, full.1, other.0, other.1].map(|pos| scale.apply(pos));
+    let full_width = full_last - full_first;
+    let other_width = other_last - other_first;
+    let to_other = |pos: f64| {
+        let pos = other_first + (pos - full_first) / full_width * other_width;
+        scale.invert(pos)
+    };
+    let (first, last) = (to_other(zoom_first), to_other(zoom_last));
+    (full_width != 0.0 && first.is_finite() && last.is_finite()).then_some((first, last))
+}
+
+/// Number of evenly spaced data values that fit across the visible range. Matches `len` unless zoomed.
+fn use_slots<T: Tick>(
+    len: Memo<usize>,
+    full: Memo<Range<T>>,
+    visible: Memo<Range<T>>,
+    scale: RwSignal<Scale>,
+) -> Memo<f64> {
+    create_memo(move |_| {
+        let scale = scale.get();
+        let full = full.with(|range| range.positions_for(scale));
+        let visible = visible.with(|range| range.positions_for(scale));
+        zoomed_len(scale, len.get(), full, visible)
+    })
+}
+
+/// Scales the number of values by the proportion of the full range that's visible. Values are assumed to be evenly spaced from first to last.
+fn zoomed_len(
+    scale: Scale,
+    len: usize,
+    full: Option<(f64, f64)>,
+    visible: Option<(f64, f64)>,
+) -> f64 {
+    let len = len as f64;
+    let width = |range: Option<(f64, f64)>| {
+        let (first, last) = range?;
+        let width = scale.apply(last) - scale.apply(first);
+        (width.is_finite() && width > 0.0).then_some(width)
+    };
+    match (width(full), width(visible)) {
+        (Some(full), Some(visible)) if len > 1.0 => (len - 1.0) * visible / full + 1.0,
+        _ => len,
+    }
+}
+
+fn use_includes_bars(series: Memo<Vec<UseY>>, orientation: BarOrientation) -> Memo<bool> {
+    create_memo(move |_| {
+        series.with(|series| {
+            series
+                .iter()
+                .filter_map(|use_y| use_y.slot_orientation())
+                .any(|slot| slot == orientation)
+        })
+    })
+}
+
 #[component]
 pub fn RenderData<X: Tick, Y: Tick>(state: State<X, Y>) -> impl IntoView {
     let data = state.pre.data.clone();
-    let mk_svg_coords = move |id| {
-        Signal::derive(move || {
-            let proj = state.projection.get();
-            data.data.with(|data| {
-                data.series_positions(id)
-                    .into_iter()
-                    .map(|(x, y)| proj.position_to_svg(x, y))
-                    .collect::<Vec<_>>()
-            })
-        })
+    let mk_svg_coords = {
+        let data = data.clone();
+        move |id, state: &State<X, Y>| {
+            let data = data.clone();
+            let projection = state.projection;
+            Signal::derive(move || data.svg_positions(id, &projection.get()))
+        }
+    };
+    let inner = state.layout.inner;
+    // Series are rendered against their Y axis
+    let mk_state = move |use_y: &UseY| state.for_y_axis(use_y.y_axis.into());
+
+    // Clip series to the inner chart area when zoomed
+    let (zoom_x, zoom_y) = (data.zoom_x, data.zoom_y);
+    let overflow = move || {
+        if zoom_x.with(Option::is_some) || zoom_y.with(Option::is_some) {
+            "hidden"
+        } else {
+            "visible"
+        }
     };
 
     view! {
-        <g class="_chartistry_series">
-            <For
-                each=move || data.series.get()
-                key=|use_y| use_y.id
-                let:use_y>
-                <RenderUseY use_y=use_y.clone() state=state.clone() positions=mk_svg_coords(use_y.id) />
-            </For>
-        </g>
+        <svg
+            x=move || inner.get().left_x()
+            y=move || inner.get().top_y()
+            width=move || inner.get().width()
+            height=move || inner.get().height()
+            viewBox=move || with!(|inner| format!("{} {} {} {}", inner.left_x(), inner.top_y(), inner.width(), inner.height()))
+            style:overflow=overflow>
+            <g class="_chartistry_series">
+                <For
+                    each=move || data.visible.get()
+                    key=|use_y| use_y.id
+                    let:use_y>
+                    {
+                        let state = mk_state(&use_y);
+                        let positions = mk_svg_coords(use_y.id, &state);
+                        view! {
+                            <RenderUseY use_y=use_y.clone() state=state positions=positions />
+                        }
+                    }
+                </For>
+            </g>
+        </svg>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Line;
+
+    #[test]
+    fn test_zoomed_len() {
+        let len = |full, visible| zoomed_len(Scale::Linear, 11, full, visible);
+        // Unzoomed
+        assert_eq!(len(Some((0.0, 10.0)), Some((0.0, 10.0))), 11.0);
+        // Two values apart
+        assert_eq!(len(Some((0.0, 10.0)), Some((4.0, 6.0))), 3.0);
+        // Empty ranges
+        assert_eq!(len(None, Some((4.0, 6.0))), 11.0);
+        assert_eq!(len(Some((5.0, 5.0)), Some((4.0, 6.0))), 11.0);
+        assert_eq!(zoomed_len(Scale::Linear, 0, None, None), 0.0);
+    }
+
+    #[test]
+    fn test_zoom_proportionally() {
+        let zoom = |scale, zoom, full, other| zoom_proportionally(scale, zoom, full, other);
+        assert_eq!(
+            zoom(Scale::Linear, (25.0, 75.0), (0.0, 100.0), (0.0, 10.0)),
+            Some((2.5, 7.5))
+        );
+        // Panned past the full range
+        assert_eq!(
+            zoom(Scale::Linear, (-50.0, 50.0), (0.0, 100.0), (10.0, 20.0)),
+            Some((5.0, 15.0))
+        );
+        // Proportional on the scale
+        let (first, last) = zoom(Scale::Log10, (10.0, 100.0), (1.0, 1000.0), (1.0, 1e6)).unwrap();
+        assert!((first - 100.0).abs() < 1e-9 && (last - 10_000.0).abs() < 1e-6);
+        // Nothing to compare
+        assert_eq!(
+            zoom(Scale::Linear, (1.0, 2.0), (5.0, 5.0), (0.0, 10.0)),
+            None
+        );
+        assert_eq!(
+            zoom(Scale::Log10, (1.0, 2.0), (-5.0, 5.0), (0.0, 10.0)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_hidden_by_id() {
+        let runtime = create_runtime();
+        // Two unnamed lines
+        let series = Series::new(|&(x, _, _): &(f64, f64, f64)| x)
+            .line(Line::new(|&(_, y1, _): &(f64, f64, f64)| y1))
+            .line(Line::new(|&(_, _, y2): &(f64, f64, f64)| y2))
+            .with_hidden([0]);
+        let data = Signal::derive(|| vec![(0.0, 1.0, 10.0), (1.0, 2.0, 20.0)]);
+        let data = UseData::new(series, data, Signal::default(), Signal::default());
+        let visible = || {
+            data.visible
+                .get()
+                .iter()
+                .map(|use_y| use_y.id)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(visible(), vec![1]);
+        assert_eq!(data.range_y.get().range(), Some((&10.0, &20.0)));
+        // Show the first, hide the second
+        data.hidden.set([1].into());
+        assert_eq!(visible(), vec![0]);
+        assert_eq!(data.range_y.get().range(), Some((&1.0, &2.0)));
+        runtime.dispose();
     }
 }
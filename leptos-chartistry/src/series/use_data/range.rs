@@ -1,14 +1,19 @@
-use crate::Tick;
+use crate::{Scale, Tick};
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct Range<T>(Option<InnerRange<T>>);
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct InnerRange<T> {
-    pub min: (T, f64),
-    pub max: (T, f64),
+    pub min: Bound<T>,
+    pub max: Bound<T>,
+    /// Smallest value above zero. Used as the minimum on a log scale
+    pub min_positive: Option<Bound<T>>,
 }
 
+/// A value and its position.
+type Bound<T> = (T, f64);
+
 impl<T> Default for Range<T> {
     fn default() -> Self {
         Self(None)
@@ -37,6 +42,21 @@ impl<T> Range<T> {
         self
     }
 
+    /// Extends this range to include another range.
+    pub fn union(mut self, other: &Self) -> Self
+    where
+        T: Tick,
+    {
+        if let Some(other) = other.0.as_ref() {
+            self.update(&other.min.0);
+            self.update(&other.max.0);
+            if let Some((min_positive, _)) = &other.min_positive {
+                self.update(min_positive);
+            }
+        }
+        self
+    }
+
     // Returns the (min, max) of T if it exists
     pub fn range(&self) -> Option<(&T, &T)> {
         self.0.as_ref().map(|r| (&r.min.0, &r.max.0))
@@ -46,6 +66,23 @@ impl<T> Range<T> {
     pub fn positions(&self) -> Option<(f64, f64)> {
         self.0.as_ref().map(|r| (r.min.1, r.max.1))
     }
+
+    /// Returns the (min, max) of T shown on the given scale. On a log scale, a min of zero or below is replaced with the smallest value above zero (if any).
+    pub fn range_for(&self, scale: Scale) -> Option<(&T, &T)> {
+        self.bounds_for(scale).map(|(min, max)| (&min.0, &max.0))
+    }
+
+    /// Returns the (min, max) of T's position shown on the given scale. See [Range::range_for].
+    pub fn positions_for(&self, scale: Scale) -> Option<(f64, f64)> {
+        self.bounds_for(scale).map(|(min, max)| (min.1, max.1))
+    }
+
+    fn bounds_for(&self, scale: Scale) -> Option<(&Bound<T>, &Bound<T>)> {
+        self.0.as_ref().map(|r| match (scale, &r.min_positive) {
+            (Scale::Log10, Some(min_positive)) if r.min.1 <= 0.0 => (min_positive, &r.max),
+            _ => (&r.min, &r.max),
+        })
+    }
 }
 
 impl<T: Tick> InnerRange<T> {
@@ -53,6 +90,7 @@ impl<T: Tick> InnerRange<T> {
         Self::position(t).map(|pos| Self {
             min: (t.clone(), pos),
             max: (t.clone(), pos),
+            min_positive: (pos > 0.0).then(|| (t.clone(), pos)),
         })
     }
 
@@ -68,6 +106,10 @@ impl<T: Tick> InnerRange<T> {
 
     pub fn update(&mut self, t: &T) {
         if let Some(pos) = Self::position(t) {
+            let below_positive = self.min_positive.as_ref().is_none_or(|min| pos < min.1);
+            if pos > 0.0 && below_positive {
+                self.min_positive = Some((t.clone(), pos));
+            }
             if *t < self.min.0 {
                 self.min = (t.clone(), pos);
             } else if *t > self.max.0 {
@@ -76,3 +118,34 @@ impl<T: Tick> InnerRange<T> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{bounds::Bounds, projection::Projection};
+
+    #[test]
+    fn test_log_min_positive() {
+        let range = Range::default().maybe_update(vec![Some(0.01), Some(0.0), Some(100.0)]);
+        assert_eq!(range.positions_for(Scale::Linear), Some((0.0, 100.0)));
+        // A single zero doesn't clip small values on a log scale
+        assert_eq!(range.range_for(Scale::Log10), Some((&0.01, &100.0)));
+        let bounds = Bounds::from_points(0.0, 0.0, 100.0, 100.0);
+        let p = Projection::scaled(
+            bounds,
+            None,
+            range.positions_for(Scale::Log10),
+            Scale::Linear,
+            Scale::Log10,
+        );
+        assert_eq!(p.position_to_svg(0.0, 0.01).1, 100.0);
+        assert_eq!(p.position_to_svg(0.0, 1.0).1, 50.0);
+        // Unions keep the smallest positive value
+        let other = Range::default().maybe_update(vec![Some(-5.0), Some(0.5)]);
+        let range = other.union(&range);
+        assert_eq!(range.range_for(Scale::Log10), Some((&0.01, &100.0)));
+        // Nothing above zero
+        let range = Range::default().maybe_update(vec![Some(-1.0), Some(0.0)]);
+        assert_eq!(range.range_for(Scale::Log10), Some((&-1.0, &0.0)));
+    }
+}
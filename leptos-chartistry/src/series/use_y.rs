@@ -1,14 +1,20 @@
+use super::YAxis;
 use super::{
     bar::{RenderBar, UseBar},
+    box_plot::{RenderBoxPlot, UseBoxPlot},
+    candlestick::{RenderCandlestick, UseCandlestick},
+    error_bars,
     line::{RenderLine, UseLine},
+    scatter::{RenderScatter, UseScatter},
 };
-use crate::{bounds::Bounds, debug::DebugRect, state::State};
+use crate::{bounds::Bounds, debug::DebugRect, state::State, BarOrientation, Tick};
 use leptos::*;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct UseY {
     pub id: usize,
     pub name: RwSignal<String>,
+    pub y_axis: RwSignal<YAxis>,
     desc: UseYDesc,
 }
 
@@ -16,26 +22,118 @@ pub struct UseY {
 enum UseYDesc {
     Line(UseLine),
     Bar(UseBar),
+    Scatter(UseScatter),
+    Candlestick(UseCandlestick),
+    BoxPlot(UseBoxPlot),
 }
 
 impl UseY {
-    pub(super) fn new_line(id: usize, name: RwSignal<String>, line: UseLine) -> Self {
+    pub(super) fn new_line(
+        id: usize,
+        name: RwSignal<String>,
+        y_axis: RwSignal<YAxis>,
+        line: UseLine,
+    ) -> Self {
         let desc = UseYDesc::Line(line);
-        Self { id, name, desc }
+        Self {
+            id,
+            name,
+            y_axis,
+            desc,
+        }
     }
 
-    pub(super) fn new_bar(id: usize, name: RwSignal<String>, bar: UseBar) -> Self {
+    pub(super) fn new_bar(
+        id: usize,
+        name: RwSignal<String>,
+        y_axis: RwSignal<YAxis>,
+        bar: UseBar,
+    ) -> Self {
         let desc = UseYDesc::Bar(bar);
-        Self { id, name, desc }
+        Self {
+            id,
+            name,
+            y_axis,
+            desc,
+        }
+    }
+
+    pub(super) fn new_scatter(
+        id: usize,
+        name: RwSignal<String>,
+        y_axis: RwSignal<YAxis>,
+        scatter: UseScatter,
+    ) -> Self {
+        let desc = UseYDesc::Scatter(scatter);
+        Self {
+            id,
+            name,
+            y_axis,
+            desc,
+        }
+    }
+
+    pub(super) fn new_candlestick(
+        id: usize,
+        name: RwSignal<String>,
+        y_axis: RwSignal<YAxis>,
+        candlestick: UseCandlestick,
+    ) -> Self {
+        let desc = UseYDesc::Candlestick(candlestick);
+        Self {
+            id,
+            name,
+            y_axis,
+            desc,
+        }
     }
 
-    pub(crate) fn bar(&self) -> Option<&UseBar> {
+    pub(super) fn new_box_plot(
+        id: usize,
+        name: RwSignal<String>,
+        y_axis: RwSignal<YAxis>,
+        box_plot: UseBoxPlot,
+    ) -> Self {
+        let desc = UseYDesc::BoxPlot(box_plot);
+        Self {
+            id,
+            name,
+            y_axis,
+            desc,
+        }
+    }
+
+    /// Orientation and group ID of the series if it shares a slot with other groups (e.g., bars).
+    pub(crate) fn slot_group(&self) -> Option<(BarOrientation, usize)> {
         match &self.desc {
-            UseYDesc::Bar(bar) => Some(bar),
+            UseYDesc::Bar(bar) => Some((bar.orientation(), bar.group_id())),
+            UseYDesc::Candlestick(candlestick) => {
+                Some((BarOrientation::Vertical, candlestick.group_id()))
+            }
+            UseYDesc::BoxPlot(box_plot) => Some((BarOrientation::Vertical, box_plot.group_id())),
             _ => None,
         }
     }
 
+    /// Orientation of the series if it takes up a slot (the width available to each X or Y value) like a bar.
+    pub(crate) fn slot_orientation(&self) -> Option<BarOrientation> {
+        match &self.desc {
+            UseYDesc::Bar(bar) => Some(bar.orientation()),
+            UseYDesc::Candlestick(_) | UseYDesc::BoxPlot(_) => Some(BarOrientation::Vertical),
+            _ => None,
+        }
+    }
+
+    /// Formats the tooltip value from the (formatted) Y value and any extra values.
+    pub(crate) fn format_values(&self, value: String, extra: &[String]) -> String {
+        match &self.desc {
+            UseYDesc::Line(_) | UseYDesc::Bar(_) => error_bars::format_values(value, extra),
+            UseYDesc::Candlestick(_) => UseCandlestick::format_values(value, extra),
+            UseYDesc::BoxPlot(_) => UseBoxPlot::format_values(value, extra),
+            UseYDesc::Scatter(_) => value,
+        }
+    }
+
     fn taster_bounds(font_height: Memo<f64>, font_width: Memo<f64>) -> Memo<Bounds> {
         create_memo(move |_| Bounds::new(font_width.get() * 2.5, font_height.get()))
     }
@@ -46,30 +144,78 @@ impl UseY {
     }
 }
 
+/// Opacity of series that aren't nearest the mouse while hovering.
+const DIM_OPACITY: f64 = 0.3;
+
 #[component]
-pub(super) fn RenderUseY<X: 'static, Y: 'static>(
+pub(super) fn RenderUseY<X: Tick, Y: Tick>(
     use_y: UseY,
     state: State<X, Y>,
     positions: Signal<Vec<(f64, f64)>>,
 ) -> impl IntoView {
+    // Emphasise the series nearest the mouse and dim the others
+    let (id, hover_series) = (use_y.id, state.hover_series);
+    let highlight = Signal::derive(move || hover_series.get() == Some(id));
+    let opacity = move || match hover_series.get() {
+        Some(hover_id) if hover_id != id => DIM_OPACITY,
+        _ => 1.0,
+    };
+
+    // Extra positions e.g., error bars
+    let extra = {
+        let data = state.pre.data.clone();
+        let projection = state.projection;
+        Signal::derive(move || data.svg_extra_positions(id, &projection.get()))
+    };
+
     let desc = use_y.desc.clone();
-    match desc {
+    let series = match desc {
         UseYDesc::Line(line) => view! {
             <RenderLine
                 use_y=use_y
-                line=line
-                data=state.pre.data
+                line=line.clone()
+                data=state.pre.data.clone()
                 positions=positions
-                markers=positions />
+                markers=positions
+                fill=line.use_fill(&state)
+                highlight=highlight
+                error=extra />
         },
         UseYDesc::Bar(bar) => view! {
-            <RenderBar bar=bar state=state positions=positions />
+            <RenderBar
+                bar=bar.clone()
+                state=state.clone()
+                positions=positions
+                stacked_on=bar.stacked_on(&state)
+                error=extra />
+        },
+        UseYDesc::Scatter(scatter) => view! {
+            <RenderScatter scatter=scatter positions=positions />
+        },
+        UseYDesc::Candlestick(candlestick) => view! {
+            <RenderCandlestick
+                group_slot=candlestick.use_slot(&state).into()
+                candlestick=candlestick
+                positions=positions
+                extra=extra />
+        },
+        UseYDesc::BoxPlot(box_plot) => view! {
+            <RenderBoxPlot
+                group_slot=box_plot.use_slot(&state).into()
+                box_plot=box_plot
+                positions=positions
+                extra=extra />
         },
+    };
+    view! {
+        <g class="_chartistry_use_y" opacity=opacity>
+            {series}
+        </g>
     }
 }
 
 #[component]
-pub fn Snippet<X: 'static, Y: 'static>(series: UseY, state: State<X, Y>) -> impl IntoView {
+pub fn Snippet<X: 'static, Y: Clone + 'static>(series: UseY, state: State<X, Y>) -> impl IntoView {
     let debug = state.pre.debug;
     let name = series.name;
     view! {
@@ -82,7 +228,7 @@ pub fn Snippet<X: 'static, Y: 'static>(series: UseY, state: State<X, Y>) -> impl
 }
 
 #[component]
-fn Taster<X: 'static, Y: 'static>(series: UseY, state: State<X, Y>) -> impl IntoView {
+fn Taster<X: 'static, Y: Clone + 'static>(series: UseY, state: State<X, Y>) -> impl IntoView {
     const Y_OFFSET: f64 = 2.0;
     let debug = state.pre.debug;
     let font_width = state.pre.font_width;
@@ -108,12 +254,74 @@ fn Taster<X: 'static, Y: 'static>(series: UseY, state: State<X, Y>) -> impl Into
                     line=line.clone()
                     data=state.pre.data
                     positions=positions
-                    markers=markers />
+                    markers=markers
+                    fill=line.use_fill_bounds(bounds) />
             }
         }
         UseYDesc::Bar(bar) => view! {
-            <RenderBar bar=bar.clone() state=state positions=positions />
+            <RenderBar bar=bar.clone() state=state positions=positions stacked_on=None />
         },
+        UseYDesc::Scatter(scatter) => {
+            // One marker in the middle
+            let markers = Signal::derive(move || {
+                let bounds = bounds.get();
+                vec![(bounds.centre_x(), bounds.centre_y() + Y_OFFSET)]
+            });
+            view! {
+                <RenderScatter scatter=scatter.clone() positions=markers />
+            }
+        }
+        UseYDesc::Candlestick(candlestick) => {
+            // One rising candle in the middle
+            let slot = Signal::derive(move || {
+                let width = bounds.get().width() / 3.0;
+                (-width / 2.0, width)
+            });
+            let close = Signal::derive(move || {
+                let bounds = bounds.get();
+                vec![(bounds.centre_x(), bounds.top_y() + bounds.height() / 4.0)]
+            });
+            let extra = Signal::derive(move || {
+                let bounds = bounds.get();
+                let open = bounds.bottom_y() - bounds.height() / 4.0;
+                vec![vec![open, bounds.top_y(), bounds.bottom_y()]]
+            });
+            view! {
+                <RenderCandlestick
+                    candlestick=candlestick.clone()
+                    group_slot=slot
+                    positions=close
+                    extra=extra />
+            }
+        }
+        UseYDesc::BoxPlot(box_plot) => {
+            // One box in the middle
+            let slot = Signal::derive(move || {
+                let width = bounds.get().width() / 2.0;
+                (-width / 2.0, width)
+            });
+            let median = Signal::derive(move || {
+                let bounds = bounds.get();
+                vec![(bounds.centre_x(), bounds.centre_y())]
+            });
+            let extra = Signal::derive(move || {
+                let bounds = bounds.get();
+                let quarter = bounds.height() / 4.0;
+                vec![vec![
+                    bounds.bottom_y(),
+                    bounds.bottom_y() - quarter,
+                    bounds.top_y() + quarter,
+                    bounds.top_y(),
+                ]]
+            });
+            view! {
+                <RenderBoxPlot
+                    box_plot=box_plot.clone()
+                    group_slot=slot
+                    positions=median
+                    extra=extra />
+            }
+        }
     };
 
     view! {
@@ -1,12 +1,13 @@
 use crate::{
     layout::Layout, projection::Projection, series::UseData, use_watched_node::UseWatchedNode,
-    Padding, Tick,
+    Padding, SyncGroup, Tick, YAxis,
 };
-use leptos::signal_prelude::*;
+use leptos::{create_effect, html::Div, on_cleanup, signal_prelude::*, window, NodeRef};
 
 #[derive(Clone)]
 pub struct PreState<X: 'static, Y: 'static> {
     pub debug: Signal<bool>,
+    pub highlight: Signal<bool>,
     pub font_height: Memo<f64>,
     pub font_width: Memo<f64>,
     pub padding: Signal<Padding>,
@@ -17,23 +18,33 @@ pub struct PreState<X: 'static, Y: 'static> {
 pub struct State<X: 'static, Y: 'static> {
     pub pre: PreState<X, Y>,
     pub layout: Layout,
+    /// Projection of the Y axis in use. See [State::for_y_axis]
     pub projection: Signal<Projection>,
+    projection_y: Signal<Projection>,
+    projection_y2: Signal<Projection>,
 
     pub svg_zero: Memo<(f64, f64)>,
 
-    /// Mouse page position
+    /// Root chart element. Used to listen for events
+    pub root: NodeRef<Div>,
+    /// Mouse page position. Follows the hover of another chart in the sync group
     pub mouse_page: Signal<(f64, f64)>,
     /// Mouse page position relative to chart
     pub mouse_chart: Signal<(f64, f64)>,
     /// Mouse over inner chart?
     pub hover_inner: Signal<bool>,
-    /// X mouse coord in data position space
+    /// Mouse over inner chart of this chart or another chart in the sync group?
+    pub hover_any: Signal<bool>,
+    /// X mouse coord in data position space. Follows the sync group
     pub hover_position_x: Memo<f64>,
+    /// ID of the visible series nearest (in Y) to the mouse. None if not hovering or highlighting is off
+    pub hover_series: Memo<Option<usize>>,
 }
 
 impl<X, Y> PreState<X, Y> {
     pub fn new(
         debug: Signal<bool>,
+        highlight: Signal<bool>,
         font_height: Memo<f64>,
         font_width: Memo<f64>,
         padding: Signal<Padding>,
@@ -41,6 +52,7 @@ impl<X, Y> PreState<X, Y> {
     ) -> Self {
         Self {
             debug,
+            highlight,
             font_height,
             font_width,
             padding,
@@ -55,28 +67,113 @@ impl<X: Tick, Y: Tick> State<X, Y> {
         node: &UseWatchedNode,
         layout: Layout,
         proj: Signal<Projection>,
+        proj_y2: Signal<Projection>,
+        sync: Option<SyncGroup>,
     ) -> Self {
         // Mouse
         let mouse_chart = node.mouse_chart;
         let hover_inner = node.mouse_hover_inner(layout.inner);
 
         // Data
-        let hover_position = create_memo(move |_| {
+        let local_x = create_memo(move |_| {
             let (mouse_x, mouse_y) = mouse_chart.get();
-            proj.get().svg_to_position(mouse_x, mouse_y)
+            proj.get().svg_to_position(mouse_x, mouse_y).0
         });
-        let hover_position_x = create_memo(move |_| hover_position.get().0);
+
+        // Share our hover with the sync group and follow others
+        let sync_id = sync.map(|sync| {
+            let id = sync.join();
+            create_effect(move |_| {
+                sync.set_hover(id, hover_inner.get().then(|| local_x.get()));
+            });
+            on_cleanup(move || sync.set_hover(id, None));
+            id
+        });
+        let sync_x = create_memo(move |_| {
+            let (sync, id) = (sync?, sync_id?);
+            (!hover_inner.get()).then(|| sync.hover_x(id)).flatten()
+        });
+        let hover_position_x = create_memo(move |_| sync_x.get().unwrap_or_else(|| local_x.get()));
+        let hover_any = Signal::derive(move || hover_inner.get() || sync_x.get().is_some());
+
+        // Position the tooltip on our chart when another chart is hovered
+        let (root, local_page) = (node.node, node.mouse_page);
+        let mouse_page = Signal::derive(move || {
+            let Some(x) = sync_x.get() else {
+                return local_page.get();
+            };
+            let Some(root) = root.get() else {
+                return local_page.get();
+            };
+            let rect = root.get_bounding_client_rect();
+            let window = window();
+            let left = rect.left() + window.scroll_x().unwrap_or_default();
+            let top = rect.top() + window.scroll_y().unwrap_or_default();
+            let (svg_x, _) = proj.get().position_to_svg(x, 0.0);
+            let inner = layout.inner.get();
+            (left + svg_x, top + inner.top_y() + inner.height() / 2.0)
+        });
+
+        // Nearest series to the mouse: compare in SVG space as series may use different Y axes
+        let hover_series = {
+            let data = pre.data.clone();
+            let highlight = pre.highlight;
+            create_memo(move |_| {
+                if !highlight.get() || !hover_inner.get() {
+                    return None;
+                }
+                let (_, mouse_y) = mouse_chart.get();
+                let pos_x = hover_position_x.get();
+                let positions = data.nearest_positions_y(pos_x);
+                data.visible.with(|series| {
+                    series
+                        .iter()
+                        .filter_map(|use_y| {
+                            let &pos_y = positions.get(&use_y.id)?;
+                            let proj = match use_y.y_axis.get() {
+                                YAxis::Secondary => proj_y2,
+                                _ => proj,
+                            };
+                            let (_, svg_y) = proj.get().position_to_svg(pos_x, pos_y);
+                            (!svg_y.is_nan()).then_some((use_y.id, (svg_y - mouse_y).abs()))
+                        })
+                        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+                        .map(|(id, _)| id)
+                })
+            })
+        };
 
         Self {
             pre,
             layout,
             projection: proj,
-            svg_zero: create_memo(move |_| proj.get().position_to_svg(0.0, 0.0)),
+            projection_y: proj,
+            projection_y2: proj_y2,
+            svg_zero: create_memo(move |_| proj.get().svg_zero()),
 
-            mouse_page: node.mouse_page,
+            root,
+            mouse_page,
             mouse_chart,
             hover_inner,
+            hover_any,
             hover_position_x,
+            hover_series,
+        }
+    }
+}
+
+impl<X: Clone, Y: Clone> State<X, Y> {
+    /// Returns a state projected against the given Y axis. X is unchanged.
+    pub fn for_y_axis(&self, y_axis: Signal<YAxis>) -> Self {
+        let (proj_y, proj_y2) = (self.projection_y, self.projection_y2);
+        let proj = Signal::derive(move || match y_axis.get() {
+            YAxis::Secondary => proj_y2.get(),
+            _ => proj_y.get(),
+        });
+        Self {
+            projection: proj,
+            svg_zero: create_memo(move |_| proj.get().svg_zero()),
+            ..self.clone()
         }
     }
 }
@@ -0,0 +1,120 @@
+use crate::bounds::Bounds;
+#[cfg(not(target_arch = "wasm32"))]
+use leptos::*;
+
+/// Renders a [Chart](crate::Chart) to a standalone SVG string without a browser. Useful for emailed reports or PDFs generated by a backend job.
+///
+/// The chart is rendered at the given outer width and height, ignoring its `aspect_ratio` prop. There's no DOM so interactive parts (e.g., tooltips, guide lines and zoom) are not shown. Use the chart's `font_width` and `font_height` props to match the font used to display the SVG. Not available on `wasm32` targets.
+///
+/// ```rust
+/// use leptos::*;
+/// use leptos_chartistry::*;
+///
+/// let svg = render_svg_string(600.0, 300.0, || {
+///     let data = Signal::derive(|| vec![(0.0, 1.0), (1.0, 3.0), (2.0, 2.0)]);
+///     view! {
+///         <Chart
+///             aspect_ratio=AspectRatio::from_env()
+///             left=TickLabels::aligned_floats()
+///             bottom=TickLabels::aligned_floats()
+///             series=Series::new(|&(x, _): &(f64, f64)| x).line(|&(_, y): &(f64, f64)| y)
+///             data=data
+///         />
+///     }
+/// });
+/// assert!(svg.starts_with("<svg"));
+/// ```
+#[cfg(not(target_arch = "wasm32"))]
+pub fn render_svg_string<F, N>(width: f64, height: f64, chart: F) -> String
+where
+    F: FnOnce() -> N + 'static,
+    N: IntoView,
+{
+    // Restore the caller's runtime afterwards e.g., when called from a server function
+    let previous = current_runtime();
+    let runtime = create_runtime();
+    // Render in our own scope so everything created is disposed of afterwards
+    let render = as_child_of_current_owner(move |chart: F| {
+        provide_context(FixedSize(Bounds::new(width, height)));
+        chart().into_view().render_to_string().to_string()
+    });
+    let (html, disposer) = render(chart);
+    drop(disposer);
+    runtime.dispose();
+    set_current_runtime(previous);
+    strip_hydration(&strip_comments(&html))
+}
+
+/// Outer size of a chart rendered without a DOM. Provided as context to [Chart](crate::Chart).
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) struct FixedSize(pub Bounds);
+
+/// Removes HTML comments (e.g., component and hydration markers) which aren't part of the SVG.
+#[cfg(not(target_arch = "wasm32"))]
+fn strip_comments(html: &str) -> String {
+    let mut svg = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(start) = rest.find("<!--") {
+        svg.push_str(&rest[..start]);
+        rest = match rest[start..].find("-->") {
+            Some(end) => &rest[start + end + 3..],
+            None => "",
+        };
+    }
+    svg.push_str(rest);
+    svg
+}
+
+/// Removes hydration keys (`data-hk` attributes) as the SVG won't be hydrated.
+#[cfg(not(target_arch = "wasm32"))]
+fn strip_hydration(html: &str) -> String {
+    const ATTR: &str = " data-hk=\"";
+    let mut svg = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(start) = rest.find(ATTR) {
+        svg.push_str(&rest[..start]);
+        let value = &rest[start + ATTR.len()..];
+        rest = match value.find('"') {
+            Some(end) => &value[end + 1..],
+            None => "",
+        };
+    }
+    svg.push_str(rest);
+    svg
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strip_comments() {
+        assert_eq!(
+            strip_comments("<svg><!-- <Chart> --><g/><!--hk=1--></svg>"),
+            "<svg><g/></svg>"
+        );
+        assert_eq!(strip_comments("<svg></svg>"), "<svg></svg>");
+        assert_eq!(strip_comments("<svg><!-- unclosed"), "<svg>");
+    }
+
+    #[test]
+    fn test_restores_runtime() {
+        let runtime = create_runtime();
+        let signal = create_rw_signal(1);
+        let svg = render_svg_string(100.0, 100.0, || view! { <svg /> });
+        assert!(svg.starts_with("<svg"));
+        // Caller's signals are still usable
+        signal.set(2);
+        assert_eq!(signal.get(), 2);
+        assert_eq!(current_runtime(), runtime);
+        runtime.dispose();
+    }
+
+    #[test]
+    fn test_strip_hydration() {
+        assert_eq!(
+            strip_hydration(r#"<svg data-hk="0-0-0-1" width="1"><g data-hk="0-0-0-2"></g></svg>"#),
+            r#"<svg width="1"><g></g></svg>"#
+        );
+    }
+}
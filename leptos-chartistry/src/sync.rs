@@ -0,0 +1,66 @@
+use leptos::*;
+
+/// Shares the hover position between charts. Hovering one chart in a group moves the guide lines and tooltips of all charts in the group to the same X value.
+///
+/// Either pass the group to each [Chart](crate::Chart) with the `sync` prop or provide it as context to all charts below a component:
+///
+/// ```rust
+/// # use leptos::*;
+/// # use leptos_chartistry::*;
+/// # #[component]
+/// # fn Dashboard() -> impl IntoView {
+/// // Charts in this component (and its children) now share hover
+/// provide_context(SyncGroup::new());
+/// # }
+/// ```
+///
+/// Charts in a group must share the same X type (e.g., timestamps) as X values are compared by their [position](crate::Tick::position).
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SyncGroup {
+    // Chart ID and X position of the chart being hovered
+    hover: RwSignal<Option<(usize, f64)>>,
+    next_id: StoredValue<usize>,
+}
+
+impl SyncGroup {
+    /// Creates a new sync group.
+    pub fn new() -> Self {
+        Self {
+            hover: RwSignal::default(),
+            next_id: store_value(0),
+        }
+    }
+
+    /// Returns a new ID for a chart joining the group.
+    pub(crate) fn join(&self) -> usize {
+        let id = self.next_id.get_value();
+        self.next_id.set_value(id + 1);
+        id
+    }
+
+    /// Sets the X position being hovered by a chart. `None` to stop hovering.
+    pub(crate) fn set_hover(&self, id: usize, position_x: Option<f64>) {
+        match position_x {
+            Some(x) => self.hover.set(Some((id, x))),
+            // Only clear our own hover: another chart may have taken over
+            None => {
+                if self.hover.with_untracked(|h| h.map(|(h_id, _)| h_id)) == Some(id) {
+                    self.hover.set(None)
+                }
+            }
+        }
+    }
+
+    /// Returns the X position being hovered by another chart in the group.
+    pub(crate) fn hover_x(&self, id: usize) -> Option<f64> {
+        self.hover
+            .get()
+            .and_then(|(h_id, x)| (h_id != id).then_some(x))
+    }
+}
+
+impl Default for SyncGroup {
+    fn default() -> Self {
+        Self::new()
+    }
+}
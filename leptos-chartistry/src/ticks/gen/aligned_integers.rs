@@ -0,0 +1,160 @@
+use super::{Format, GeneratedTicks, Generator, Span};
+use std::marker::PhantomData;
+
+/// Generates integer ticks. Never produces fractional ticks and aligns to nice steps (1, 2, 5, 10, 20, 50, etc).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AlignedIntegers<I> {
+    int: PhantomData<I>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct State<I>(PhantomData<I>);
+
+macro_rules! impl_aligned_integers {
+    ($($ty:ty),*) => {
+        $(
+            impl Generator for AlignedIntegers<$ty> {
+                type Tick = $ty;
+
+                fn generate(
+                    &self,
+                    &first: &Self::Tick,
+                    &last: &Self::Tick,
+                    span: &dyn Span<Self::Tick>,
+                ) -> GeneratedTicks<Self::Tick> {
+                    let state = State::<$ty>(PhantomData);
+                    // Most ticks that could fit: every label is at least as wide as "0"
+                    let min_width = span.consumed(&state, &[0 as $ty]);
+                    let max_count = (span.length() / min_width).max(0.0) as i128;
+                    let ticks = generate(first as i128, last as i128, max_count, |ticks| {
+                        let ticks = ticks.iter().map(|&tick| tick as $ty).collect::<Vec<_>>();
+                        span.consumed(&state, &ticks) <= span.length()
+                    });
+                    let ticks = ticks.into_iter().map(|tick| tick as $ty).collect();
+                    GeneratedTicks::new(state, ticks)
+                }
+            }
+
+            impl Format for State<$ty> {
+                type Tick = $ty;
+
+                fn format(&self, value: &Self::Tick) -> String {
+                    value.to_string()
+                }
+            }
+        )*
+    };
+}
+
+impl_aligned_integers!(i32, i64, u32, u64, usize);
+
+/// Generates ticks on the smallest nice step (1, 2, 5, 10, etc) that fits. Steps giving more than `max_count` ticks are skipped without generating them. Falls back to a single tick in the middle.
+fn generate(first: i128, last: i128, max_count: i128, fits: impl Fn(&[i128]) -> bool) -> Vec<i128> {
+    let (first, last) = (first.min(last), first.max(last));
+    let range = last - first;
+    // Start from the magnitude of the smallest step that could fit
+    let min_step = range / max_count.max(1);
+    let mut magnitude = 1;
+    while magnitude * 10 <= min_step {
+        magnitude *= 10;
+    }
+    loop {
+        for mult in [1, 2, 5] {
+            let step = magnitude * mult;
+            // At least range / step ticks
+            if range / step <= max_count {
+                let ticks = step_ticks(first, last, step);
+                if !ticks.is_empty() && fits(&ticks) {
+                    return ticks;
+                }
+            }
+            // Nothing left to try
+            if step > range {
+                return vec![first + range / 2];
+            }
+        }
+        magnitude *= 10;
+    }
+}
+
+/// Multiples of step between first and last inclusive.
+fn step_ticks(first: i128, last: i128, step: i128) -> Vec<i128> {
+    let start = first.div_euclid(step) * step;
+    let start = if start < first { start + step } else { start };
+    (0..)
+        .map(|i| start + i * step)
+        .take_while(|&tick| tick <= last)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::HorizontalSpan;
+    use super::*;
+
+    fn assert_ticks(first: i64, last: i64, width: f64, expected: Vec<i64>) {
+        let span = HorizontalSpan::new(1.0, 0, 0.0, width, HorizontalSpan::identity_format());
+        let gen = AlignedIntegers::<i64>::default().generate(&first, &last, &span);
+        assert_eq!(
+            gen.ticks, expected,
+            "first={first}, last={last}, width={width}"
+        );
+    }
+
+    #[test]
+    fn test_step_ticks() {
+        assert_eq!(step_ticks(0, 10, 5), vec![0, 5, 10]);
+        assert_eq!(step_ticks(1, 9, 5), vec![5]);
+        assert_eq!(step_ticks(-7, 7, 5), vec![-5, 0, 5]);
+        assert_eq!(step_ticks(3, 4, 5), Vec::<i128>::new());
+    }
+
+    #[test]
+    fn test_generate() {
+        assert_ticks(0, 5, 100.0, vec![0, 1, 2, 3, 4, 5]);
+        assert_ticks(0, 5, 3.0, vec![0, 2, 4]);
+        assert_ticks(0, 5, 1.0, vec![0]);
+        assert_ticks(0, 100, 9.0, vec![0, 50, 100]);
+        assert_ticks(3, 3, 100.0, vec![3]);
+        assert_ticks(1, 2, 0.0, vec![1]);
+        assert_ticks(-10, 10, 100.0, (-10..=10).collect());
+        assert_ticks(-10, 10, 25.0, vec![-10, -5, 0, 5, 10]);
+    }
+
+    #[test]
+    fn test_wide_range() {
+        // e.g., millisecond timestamps
+        assert_ticks(
+            0,
+            1_000_000_000_000,
+            60.0,
+            vec![0, 500_000_000_000, 1_000_000_000_000],
+        );
+        let first = 1_700_000_000_000;
+        assert_ticks(
+            first,
+            first + 1_000_000_000_000,
+            15.0,
+            vec![2_000_000_000_000],
+        );
+        // Skips steps with too many ticks
+        assert_eq!(
+            generate(0, 1_000_000_000_000, 2, |_| true),
+            vec![0, 500_000_000_000, 1_000_000_000_000]
+        );
+    }
+
+    #[test]
+    fn test_no_fractions() {
+        // A range of 0..=1 across a wide span would give 0.5 steps with floats
+        assert_ticks(0, 1, 1000.0, vec![0, 1]);
+    }
+
+    #[test]
+    fn test_unsigned() {
+        let span = HorizontalSpan::new(1.0, 0, 0.0, 100.0, HorizontalSpan::identity_format());
+        let gen = AlignedIntegers::<u32>::default().generate(&0_u32, &3_u32, &span);
+        assert_eq!(gen.ticks, vec![0_u32, 1, 2, 3]);
+        assert_eq!(gen.state.format(&3), "3");
+    }
+}
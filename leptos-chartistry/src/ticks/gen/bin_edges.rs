@@ -0,0 +1,123 @@
+use super::{Format, GeneratedTicks, Generator, Span};
+use leptos::*;
+
+/// Generates ticks on the edges of histogram bins. Emits one tick per edge, skipping edges when there's not enough space. Created by [Histogram::tick_labels](crate::Histogram::tick_labels).
+#[derive(Clone, Debug, PartialEq)]
+pub struct BinEdges {
+    edges: Signal<Vec<f64>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct State {
+    precision: usize,
+}
+
+impl BinEdges {
+    /// Create a new generator from bin edges in ascending order.
+    pub(crate) fn new(edges: Signal<Vec<f64>>) -> Self {
+        Self { edges }
+    }
+}
+
+impl Generator for BinEdges {
+    type Tick = f64;
+
+    fn generate(
+        &self,
+        _first: &Self::Tick,
+        _last: &Self::Tick,
+        span: &dyn Span<Self::Tick>,
+    ) -> GeneratedTicks<Self::Tick> {
+        let all = self.edges.get();
+        if all.is_empty() {
+            return GeneratedTicks::none();
+        }
+        let state = State::new(&all);
+
+        // Skip every nth edge until they fit. Start from a naive estimate
+        let consumed = span.consumed(&state, &all);
+        let mut step = (consumed / span.length()).ceil().max(1.0) as usize;
+        loop {
+            let ticks = all.iter().step_by(step).copied().collect::<Vec<_>>();
+            if ticks.len() <= 1 || span.consumed(&state, &ticks) <= span.length() {
+                return GeneratedTicks::new(state, ticks);
+            }
+            step += 1;
+        }
+    }
+}
+
+impl State {
+    /// Finds the fewest decimal places that shows each edge exactly. Limited to one more than needed to tell edges apart.
+    fn new(edges: &[f64]) -> Self {
+        let width = edges
+            .windows(2)
+            .map(|w| w[1] - w[0])
+            .fold(f64::INFINITY, f64::min);
+        let max_precision = if width.is_finite() && width > 0.0 {
+            (-width.log10()).ceil().max(0.0) as usize + 1
+        } else {
+            0
+        };
+        let precision = (0..max_precision)
+            .find(|&precision| {
+                let scale = 10_f64.powi(precision as i32);
+                edges
+                    .iter()
+                    .all(|edge| ((edge * scale).round() - edge * scale).abs() < 1e-9)
+            })
+            .unwrap_or(max_precision);
+        Self { precision }
+    }
+}
+
+impl Format for State {
+    type Tick = f64;
+
+    fn format(&self, value: &Self::Tick) -> String {
+        format!("{:.*}", self.precision, value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::HorizontalSpan;
+    use super::*;
+
+    fn assert_ticks(edges: &[f64], width: f64, expected: &[&str]) {
+        let runtime = create_runtime();
+        let span = HorizontalSpan::new(1.0, 0, 0.0, width, HorizontalSpan::identity_format());
+        let edges = BinEdges::new(Signal::derive({
+            let edges = edges.to_vec();
+            move || edges.clone()
+        }));
+        let gen = edges.generate(&0.0, &0.0, &span);
+        let labels = gen
+            .ticks
+            .iter()
+            .map(|tick| gen.state.format(tick))
+            .collect::<Vec<_>>();
+        assert_eq!(labels, expected);
+        runtime.dispose();
+    }
+
+    #[test]
+    fn test_precision() {
+        assert_eq!(State::new(&[0.0, 5.0, 10.0]).precision, 0);
+        assert_eq!(State::new(&[0.5, 1.0, 1.5]).precision, 1);
+        assert_eq!(State::new(&[0.25, 0.5, 0.75]).precision, 2);
+        // Limited
+        assert_eq!(State::new(&[1.234, 6.234]).precision, 1);
+        assert_eq!(State::new(&[1.0 / 3.0, 2.0 / 3.0]).precision, 2);
+    }
+
+    #[test]
+    fn test_generate() {
+        let edges = [0.0, 1.0, 2.0, 3.0, 4.0];
+        assert_ticks(&edges, 100.0, &["0", "1", "2", "3", "4"]);
+        assert_ticks(&edges, 5.0, &["0", "1", "2", "3", "4"]);
+        assert_ticks(&edges, 4.0, &["0", "2", "4"]);
+        assert_ticks(&edges, 1.0, &["0"]);
+        assert_ticks(&[], 100.0, &[]);
+    }
+}
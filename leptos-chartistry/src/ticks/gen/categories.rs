@@ -0,0 +1,207 @@
+use super::{Format, GeneratedTicks, Generator, Span};
+use std::rc::Rc;
+
+/// An ordered set of categories (e.g., names or enum variants). Use to create [Category] ticks for a categorical axis.
+///
+/// # Example
+/// ```rust
+/// # use leptos_chartistry::*;
+/// # struct MyData { method: &'static str, requests: f64 }
+/// let methods = Categories::new(["GET", "POST", "PUT", "DELETE"]);
+/// let series = Series::new(move |data: &MyData| methods.get(data.method))
+///     .bar(|data: &MyData| data.requests);
+/// ```
+/// See this in action with the [categorical axis example](https://feral-dot-io.github.io/leptos-chartistry/examples.html#categorical-axis).
+#[derive(Clone, Debug, PartialEq, PartialOrd)]
+pub struct Categories {
+    labels: Rc<[String]>,
+}
+
+/// A single category from a set of [Categories]. Positioned on an axis by its order in the set.
+#[derive(Clone, Debug, PartialEq, PartialOrd)]
+pub struct Category {
+    ordinal: Option<usize>,
+    categories: Categories,
+}
+
+/// Generates category ticks. Emits one tick per category, skipping categories when there's not enough space.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CategoryTicks {}
+
+#[derive(Clone, Debug, PartialEq)]
+struct State;
+
+impl Categories {
+    /// Create a new set of categories. The order given is the order they appear on the axis.
+    pub fn new<S: ToString>(labels: impl IntoIterator<Item = S>) -> Self {
+        Self {
+            labels: labels.into_iter().map(|label| label.to_string()).collect(),
+        }
+    }
+
+    /// Returns the category with the given label. Unknown labels result in a missing category (not shown on the chart).
+    pub fn get(&self, label: impl AsRef<str>) -> Category {
+        let label = label.as_ref();
+        Category {
+            ordinal: self.labels.iter().position(|l| l == label),
+            categories: self.clone(),
+        }
+    }
+
+    /// Returns the category at the given position in the set. Out of bounds results in a missing category (not shown on the chart).
+    pub fn at(&self, ordinal: usize) -> Category {
+        Category {
+            ordinal: (ordinal < self.labels.len()).then_some(ordinal),
+            categories: self.clone(),
+        }
+    }
+
+    /// Returns the number of categories.
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    /// Returns true if there are no categories.
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+}
+
+impl Category {
+    /// Returns the position of the category in its set. `None` if the category is missing.
+    pub fn ordinal(&self) -> Option<usize> {
+        self.ordinal
+    }
+
+    /// Returns the label of the category. `None` if the category is missing.
+    pub fn label(&self) -> Option<&str> {
+        self.ordinal
+            .map(|ordinal| self.categories.labels[ordinal].as_str())
+    }
+
+    /// Returns the category from the same set nearest to the given position. `None` if the set is empty.
+    pub(crate) fn nearest(&self, position: f64) -> Option<Self> {
+        let last = self.categories.len().checked_sub(1)?;
+        let ordinal = position.round().clamp(0.0, last as f64) as usize;
+        Some(self.categories.at(ordinal))
+    }
+}
+
+impl std::fmt::Display for Category {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.label().unwrap_or("-"))
+    }
+}
+
+impl Generator for CategoryTicks {
+    type Tick = Category;
+
+    fn generate(
+        &self,
+        first: &Self::Tick,
+        last: &Self::Tick,
+        span: &dyn Span<Self::Tick>,
+    ) -> GeneratedTicks<Self::Tick> {
+        let categories = &first.categories;
+        let (Some(first), Some(last)) = (first.ordinal, last.ordinal) else {
+            return GeneratedTicks::none();
+        };
+        let all = (first..=last)
+            .map(|ordinal| categories.at(ordinal))
+            .collect::<Vec<_>>();
+
+        // Skip every nth category until they fit. Start from a naive estimate
+        let consumed = span.consumed(&State, &all);
+        let mut step = (consumed / span.length()).ceil().max(1.0) as usize;
+        loop {
+            let ticks = all.iter().step_by(step).cloned().collect::<Vec<_>>();
+            if ticks.len() <= 1 || span.consumed(&State, &ticks) <= span.length() {
+                return GeneratedTicks::new(State, ticks);
+            }
+            step += 1;
+        }
+    }
+}
+
+impl Format for State {
+    type Tick = Category;
+
+    fn format(&self, value: &Self::Tick) -> String {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::HorizontalSpan;
+    use super::*;
+
+    fn mk_span(width: f64) -> Box<dyn Span<Category>> {
+        Box::new(HorizontalSpan::new(
+            1.0,
+            0,
+            0.0,
+            width,
+            HorizontalSpan::identity_format(),
+        ))
+    }
+
+    fn assert_ticks(categories: &Categories, width: f64, expected: &[&str]) {
+        let first = categories.at(0);
+        let last = categories.at(categories.len() - 1);
+        let gen = CategoryTicks::default().generate(&first, &last, mk_span(width).as_ref());
+        let labels = gen
+            .ticks
+            .iter()
+            .map(|tick| gen.state.format(tick))
+            .collect::<Vec<_>>();
+        assert_eq!(labels, expected);
+    }
+
+    #[test]
+    fn test_nearest() {
+        let months = Categories::new(["Jan", "Feb", "Mar"]);
+        let jan = months.at(0);
+        assert_eq!(jan.nearest(1.4), Some(months.at(1)));
+        assert_eq!(jan.nearest(-3.0), Some(months.at(0)));
+        assert_eq!(jan.nearest(9.0), Some(months.at(2)));
+        assert_eq!(
+            Categories::new(Vec::<String>::new()).at(0).nearest(0.0),
+            None
+        );
+    }
+
+    #[test]
+    fn test_categories() {
+        let categories = Categories::new(["a", "b", "c"]);
+        assert_eq!(categories.len(), 3);
+        assert_eq!(categories.get("b").ordinal(), Some(1));
+        assert_eq!(categories.get("b").label(), Some("b"));
+        assert_eq!(categories.get("z").ordinal(), None);
+        assert_eq!(categories.at(3).label(), None);
+        assert!(categories.get("a") < categories.get("c"));
+        assert_eq!(categories.get("c"), categories.at(2));
+    }
+
+    #[test]
+    fn test_generate() {
+        let categories = Categories::new(["aa", "bb", "cc", "dd", "ee"]);
+        assert_ticks(&categories, 100.0, &["aa", "bb", "cc", "dd", "ee"]);
+        assert_ticks(&categories, 10.0, &["aa", "bb", "cc", "dd", "ee"]);
+        assert_ticks(&categories, 9.0, &["aa", "cc", "ee"]);
+        assert_ticks(&categories, 6.0, &["aa", "cc", "ee"]);
+        assert_ticks(&categories, 5.0, &["aa", "dd"]);
+        assert_ticks(&categories, 1.0, &["aa"]);
+    }
+
+    #[test]
+    fn test_generate_missing() {
+        let categories = Categories::new(["a", "b"]);
+        let gen = CategoryTicks::default().generate(
+            &categories.get("z"),
+            &categories.at(1),
+            mk_span(100.0).as_ref(),
+        );
+        assert!(gen.ticks.is_empty());
+    }
+}
@@ -0,0 +1,179 @@
+use super::{Format, GeneratedTicks, Generator, Span};
+
+/// Generates f64 ticks for a log scale. Emits decades (1, 10, 100, etc) with sub-decade ticks (2, 5 or 2-9) when there's space. Use with [Scale::Log10](crate::Scale::Log10) or [Scale::SymLog](crate::Scale::SymLog).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LogFloats {}
+
+#[derive(Clone, Debug, PartialEq)]
+struct State;
+
+// Sub-decade multipliers to try, from densest to sparsest
+const MULTIPLIERS: [&[u32]; 3] = [&[1, 2, 3, 4, 5, 6, 7, 8, 9], &[1, 2, 5], &[1]];
+
+impl Generator for LogFloats {
+    type Tick = f64;
+
+    fn generate(
+        &self,
+        &first: &Self::Tick,
+        &last: &Self::Tick,
+        span: &dyn Span<Self::Tick>,
+    ) -> GeneratedTicks<Self::Tick> {
+        let fits = |ticks: &[f64]| span.consumed(&State, ticks) <= span.length();
+        // Try sub-decades
+        for mults in MULTIPLIERS {
+            let ticks = generate(first, last, mults, 1);
+            if fits(&ticks) {
+                return GeneratedTicks::new(State, ticks);
+            }
+        }
+        // Skip decades until they fit
+        let mut skip = 2;
+        loop {
+            let ticks = generate(first, last, &[1], skip);
+            if ticks.len() <= 1 || fits(&ticks) {
+                return GeneratedTicks::new(State, ticks);
+            }
+            skip += 1;
+        }
+    }
+}
+
+/// Generates ticks between first and last inclusive. Ranges touching zero (only possible with symlog) use decades from 1 and include zero.
+fn generate(first: f64, last: f64, mults: &[u32], skip: usize) -> Vec<f64> {
+    let (first, last) = (first.min(last), first.max(last));
+    if first > 0.0 {
+        decades(first, last, mults, skip)
+    } else if last < 0.0 {
+        negate(decades(-last, -first, mults, skip))
+    } else {
+        let mut ticks = negate(decades(1.0, -first, mults, skip));
+        ticks.push(0.0);
+        ticks.extend(decades(1.0, last, mults, skip));
+        ticks
+    }
+}
+
+fn negate(ticks: Vec<f64>) -> Vec<f64> {
+    ticks.into_iter().rev().map(|tick| -tick).collect()
+}
+
+/// Positive ticks between first and last inclusive. Assumes 0 < first <= last.
+fn decades(first: f64, last: f64, mults: &[u32], skip: usize) -> Vec<f64> {
+    if !(first.is_finite() && last.is_finite()) || first > last {
+        return vec![];
+    }
+    let from = first.log10().floor() as i32;
+    let to = last.log10().floor() as i32;
+    (from..=to)
+        .filter(|exp| exp.rem_euclid(skip as i32) == 0)
+        .flat_map(|exp| {
+            mults
+                .iter()
+                .map(move |&mult| mult as f64 * 10_f64.powi(exp))
+        })
+        .filter(|&tick| first <= tick && tick <= last)
+        .collect()
+}
+
+impl Format for State {
+    type Tick = f64;
+
+    fn format(&self, value: &Self::Tick) -> String {
+        if value.is_nan() {
+            return "-".to_string();
+        }
+        if *value == 0.0 {
+            return "0".to_string();
+        }
+        // Recover the multiplier and exponent. Nudge to avoid f64 errors e.g., log10(0.001)
+        let exp = (value.abs().log10() + 1e-9).floor() as i32;
+        let mult = value / 10_f64.powi(exp);
+        if (-4..6).contains(&exp) {
+            let precision = (-exp).max(0) as usize;
+            format!("{value:.precision$}")
+        } else {
+            format!("{}e{exp}", mult.round())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::HorizontalSpan;
+    use super::*;
+
+    fn assert_ticks(first: f64, last: f64, width: f64, expected: &[&str]) {
+        let span = HorizontalSpan::new(1.0, 0, 0.0, width, HorizontalSpan::identity_format());
+        let gen = LogFloats::default().generate(&first, &last, &span);
+        let labels = gen
+            .ticks
+            .iter()
+            .map(|tick| gen.state.format(tick))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            labels, expected,
+            "first={first}, last={last}, width={width}"
+        );
+    }
+
+    #[test]
+    fn test_decades() {
+        assert_eq!(
+            decades(1.0, 1000.0, &[1], 1),
+            vec![1.0, 10.0, 100.0, 1000.0]
+        );
+        assert_eq!(
+            decades(5.0, 500.0, &[1, 5], 1),
+            vec![5.0, 10.0, 50.0, 100.0, 500.0]
+        );
+        assert_eq!(decades(1.0, 1000.0, &[1], 2), vec![1.0, 100.0]);
+        assert_eq!(decades(2.0, 1.0, &[1], 1), Vec::<f64>::new());
+    }
+
+    #[test]
+    fn test_generate() {
+        let all = ["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "20"];
+        assert_ticks(1.0, 20.0, 100.0, &all);
+        assert_ticks(1.0, 20.0, 10.0, &["1", "2", "5", "10", "20"]);
+        assert_ticks(1.0, 20.0, 3.0, &["1"]);
+        assert_ticks(1.0, 10_000.0, 15.0, &["1", "100", "10000"]);
+        assert_ticks(0.001, 0.1, 100.0, &all_decimals());
+    }
+
+    fn all_decimals() -> Vec<&'static str> {
+        vec![
+            "0.001", "0.002", "0.003", "0.004", "0.005", "0.006", "0.007", "0.008", "0.009",
+            "0.01", "0.02", "0.03", "0.04", "0.05", "0.06", "0.07", "0.08", "0.09", "0.1",
+        ]
+    }
+
+    #[test]
+    fn test_symlog() {
+        assert_ticks(
+            -100.0,
+            100.0,
+            30.0,
+            &["-100", "-10", "-1", "0", "1", "10", "100"],
+        );
+        assert_ticks(-0.5, 0.5, 30.0, &["0"]);
+        assert_ticks(
+            -100.0,
+            -1.0,
+            30.0,
+            &["-100", "-50", "-20", "-10", "-5", "-2", "-1"],
+        );
+    }
+
+    #[test]
+    fn test_format() {
+        let format = |value: f64| State.format(&value);
+        assert_eq!(format(1.0), "1");
+        assert_eq!(format(300.0), "300");
+        assert_eq!(format(0.3 * 0.1), "0.03");
+        assert_eq!(format(1e6), "1e6");
+        assert_eq!(format(2e-7), "2e-7");
+        assert_eq!(format(-5e9), "-5e9");
+        assert_eq!(format(0.0), "0");
+    }
+}
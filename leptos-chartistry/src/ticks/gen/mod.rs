@@ -1,8 +1,16 @@
 mod aligned_floats;
+mod aligned_integers;
+mod bin_edges;
+mod categories;
+mod log_floats;
 mod span;
 mod timestamps;
 
 pub use aligned_floats::AlignedFloats;
+pub use aligned_integers::AlignedIntegers;
+pub use bin_edges::BinEdges;
+pub use categories::{Categories, Category, CategoryTicks};
+pub use log_floats::LogFloats;
 pub use span::{HorizontalSpan, TickFormatFn, VerticalSpan};
 pub use timestamps::{Period, Timestamps};
 
@@ -1,8 +1,9 @@
 mod gen;
 
 pub use gen::{
-    AlignedFloats, Format as TickFormat, GeneratedTicks, Generator as TickGen, HorizontalSpan,
-    Period, TickFormatFn, Timestamps, VerticalSpan,
+    AlignedFloats, AlignedIntegers, BinEdges, Categories, Category, CategoryTicks,
+    Format as TickFormat, GeneratedTicks, Generator as TickGen, HorizontalSpan, LogFloats, Period,
+    TickFormatFn, Timestamps, VerticalSpan,
 };
 
 use chrono::prelude::*;
@@ -19,6 +20,11 @@ pub trait Tick: Clone + PartialEq + PartialOrd + std::fmt::Debug + 'static {
 
     /// Maps the tick to a position on the axis. Must be uniform. May return `f64::NAN` for missing data.
     fn position(&self) -> f64;
+
+    /// Maps a position on the axis back to a tick. The inverse of [Tick::position]. Uses `self` for any context a position lacks (e.g., a timezone). Used by interactions such as [Zoom](crate::Zoom). Default returns `None` (not supported).
+    fn at_position(&self, _position: f64) -> Option<Self> {
+        None
+    }
 }
 
 impl Tick for f64 {
@@ -29,6 +35,46 @@ impl Tick for f64 {
     fn position(&self) -> f64 {
         *self
     }
+
+    fn at_position(&self, position: f64) -> Option<Self> {
+        Some(position)
+    }
+}
+
+macro_rules! impl_tick_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl Tick for $ty {
+                fn tick_label_generator() -> impl TickGen<Tick = Self> {
+                    AlignedIntegers::<Self>::default()
+                }
+
+                fn position(&self) -> f64 {
+                    *self as f64
+                }
+
+                fn at_position(&self, position: f64) -> Option<Self> {
+                    position.is_finite().then(|| position.round() as $ty)
+                }
+            }
+        )*
+    };
+}
+
+impl_tick_integer!(i32, i64, u32, u64, usize);
+
+impl Tick for Category {
+    fn tick_label_generator() -> impl TickGen<Tick = Self> {
+        CategoryTicks::default()
+    }
+
+    fn position(&self) -> f64 {
+        self.ordinal().map_or(f64::NAN, |ordinal| ordinal as f64)
+    }
+
+    fn at_position(&self, position: f64) -> Option<Self> {
+        self.nearest(position)
+    }
 }
 
 impl<Tz> Tick for DateTime<Tz>
@@ -47,4 +93,10 @@ where
     fn position(&self) -> f64 {
         self.timestamp() as f64 + (self.timestamp_subsec_nanos() as f64 / 1e9)
     }
+
+    fn at_position(&self, position: f64) -> Option<Self> {
+        let secs = position.floor();
+        let nanos = ((position - secs) * 1e9) as u32;
+        self.timezone().timestamp_opt(secs as i64, nanos).single()
+    }
 }
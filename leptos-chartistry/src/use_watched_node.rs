@@ -7,8 +7,9 @@ use leptos_use::{
 use std::convert::Infallible;
 use web_sys::ResizeObserverBoxOptions;
 
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct UseWatchedNode {
+    pub node: NodeRef<Div>,
     pub bounds: Signal<Option<Bounds>>,
     pub mouse_page: Signal<(f64, f64)>,
     pub mouse_chart: Signal<(f64, f64)>,
@@ -84,6 +85,7 @@ pub fn use_watched_node(node: NodeRef<Div>) -> UseWatchedNode {
     .into();
 
     UseWatchedNode {
+        node,
         bounds,
         mouse_page,
         mouse_chart,
@@ -92,6 +94,17 @@ pub fn use_watched_node(node: NodeRef<Div>) -> UseWatchedNode {
 }
 
 impl UseWatchedNode {
+    /// A node of fixed size that is never hovered. Used to render without a DOM.
+    pub fn fixed(node: NodeRef<Div>, bounds: Bounds) -> Self {
+        UseWatchedNode {
+            node,
+            bounds: Signal::derive(move || Some(bounds)),
+            mouse_page: Signal::derive(|| (0.0, 0.0)),
+            mouse_chart: Signal::derive(|| (0.0, 0.0)),
+            mouse_chart_hover: Signal::derive(|| false),
+        }
+    }
+
     // Mouse inside inner chart?
     pub fn mouse_hover_inner(&self, inner: Memo<Bounds>) -> Signal<bool> {
         let (mouse_rel, hover) = (self.mouse_chart, self.mouse_chart_hover);